@@ -0,0 +1,390 @@
+use crate::{matrix::Matrix, vector::Vector, Complex};
+
+/// Approximate comparisons with an absolute, a relative or an ULP tolerance.
+pub trait ApproxEq {
+    /// The type in which absolute and relative tolerances are expressed.
+    type Tolerance: Copy + std::fmt::Debug;
+
+    /// Returns the absolute tolerance used when none is given.
+    fn default_epsilon() -> Self::Tolerance;
+
+    /// Checks if two values differ by at most an absolute tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to compare with.
+    /// * `epsilon` - The largest accepted difference.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are within `epsilon` of each other, `false` otherwise.
+    fn approx_eq_abs(&self, other: &Self, epsilon: Self::Tolerance) -> bool;
+
+    /// Checks if two values differ by at most a fraction of the largest one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to compare with.
+    /// * `max_relative` - The largest accepted difference, relative to the largest
+    ///   modulus of the two values.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are within `max_relative` of each other, `false` otherwise.
+    fn approx_eq_rel(&self, other: &Self, max_relative: Self::Tolerance) -> bool;
+
+    /// Checks if two values are at most a number of representable floats apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to compare with.
+    /// * `max_ulps` - The largest accepted number of units in the last place.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are within `max_ulps` of each other, `false` otherwise.
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool;
+
+    /// Checks if two values differ by at most the default tolerance, either absolutely or
+    /// relative to the largest one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to compare with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are within `default_epsilon()` of each other, absolutely or
+    /// relatively, `false` otherwise.
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_abs(other, Self::default_epsilon())
+            || self.approx_eq_rel(other, Self::default_epsilon())
+    }
+}
+
+macro_rules! impl_float_approx_eq {
+    ($($t:ty, $bits:ty, $epsilon:expr);*) => {$(
+        impl ApproxEq for $t {
+            type Tolerance = $t;
+
+            fn default_epsilon() -> $t {
+                $epsilon
+            }
+
+            fn approx_eq_abs(&self, other: &Self, epsilon: $t) -> bool {
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn approx_eq_rel(&self, other: &Self, max_relative: $t) -> bool {
+                self == other || (self - other).abs() <= max_relative * self.abs().max(other.abs())
+            }
+
+            fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                (self.to_bits() as $bits).abs_diff(other.to_bits() as $bits) <= max_ulps.into()
+            }
+        }
+
+        impl ApproxEq for Complex<$t> {
+            type Tolerance = $t;
+
+            fn default_epsilon() -> $t {
+                $epsilon
+            }
+
+            fn approx_eq_abs(&self, other: &Self, epsilon: $t) -> bool {
+                self == other || (self.0 - other.0).norm() <= epsilon
+            }
+
+            fn approx_eq_rel(&self, other: &Self, max_relative: $t) -> bool {
+                self == other
+                    || (self.0 - other.0).norm() <= max_relative * self.0.norm().max(other.0.norm())
+            }
+
+            fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+                self.0.re.approx_eq_ulps(&other.0.re, max_ulps)
+                    && self.0.im.approx_eq_ulps(&other.0.im, max_ulps)
+            }
+        }
+    )*};
+}
+
+impl_float_approx_eq!(f32, i32, 1e-6; f64, i64, 1e-12);
+
+impl<K: ApproxEq> ApproxEq for Vector<K> {
+    type Tolerance = K::Tolerance;
+
+    fn default_epsilon() -> K::Tolerance {
+        K::default_epsilon()
+    }
+
+    fn approx_eq_abs(&self, other: &Self, epsilon: K::Tolerance) -> bool {
+        self.is_same_size(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_abs(b, epsilon))
+    }
+
+    fn approx_eq_rel(&self, other: &Self, max_relative: K::Tolerance) -> bool {
+        self.is_same_size(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_rel(b, max_relative))
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.is_same_size(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_ulps(b, max_ulps))
+    }
+}
+
+impl<K: ApproxEq> ApproxEq for Matrix<K> {
+    type Tolerance = K::Tolerance;
+
+    fn default_epsilon() -> K::Tolerance {
+        K::default_epsilon()
+    }
+
+    fn approx_eq_abs(&self, other: &Self, epsilon: K::Tolerance) -> bool {
+        self.is_same_shape(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_abs(b, epsilon))
+    }
+
+    fn approx_eq_rel(&self, other: &Self, max_relative: K::Tolerance) -> bool {
+        self.is_same_shape(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_rel(b, max_relative))
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.is_same_shape(other)
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq_ulps(b, max_ulps))
+    }
+}
+
+/// Returns the default absolute tolerance of the type of a value.
+///
+/// # Arguments
+///
+/// * `_value` - A value of the type whose tolerance is wanted.
+///
+/// # Returns
+///
+/// The default absolute tolerance of the type.
+#[doc(hidden)]
+pub fn default_epsilon_of<T: ApproxEq>(_value: &T) -> T::Tolerance {
+    T::default_epsilon()
+}
+
+/// Shared implementation of the approximate assertion macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx_eq {
+    ($ty:path, $left:expr, $right:expr, $check:ident, @default, $mode:literal) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let left: &$ty = left;
+                $crate::__assert_approx_eq!(
+                    $ty,
+                    left,
+                    right,
+                    $check,
+                    $crate::approx::default_epsilon_of(left),
+                    $mode
+                )
+            }
+        }
+    };
+    ($ty:path, $left:expr, $right:expr, $check:ident, $tolerance:expr, $mode:literal) => {
+        match ($left, $right) {
+            (left, right) => {
+                let (left, right): (&$ty, &$ty) = (left, right);
+                let tolerance = $tolerance;
+                if !$crate::approx::ApproxEq::$check(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed ({} <= {:?})\n left:\n{}right:\n{}",
+                        $mode, tolerance, left, right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two Vectors have the same size and approximately equal elements.
+///
+/// The tolerance defaults to `ApproxEq::default_epsilon()` and can be given as
+/// `abs = epsilon`, `rel = max_relative` or `ulps = max_ulps`. Both operands are printed
+/// on failure.
+#[macro_export]
+macro_rules! assert_vector_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::vector::Vector<_>,
+            &$left,
+            &$right,
+            approx_eq_abs,
+            @default,
+            "abs"
+        )
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::vector::Vector<_>,
+            &$left,
+            &$right,
+            approx_eq_abs,
+            $epsilon,
+            "abs"
+        )
+    };
+    ($left:expr, $right:expr, rel = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::vector::Vector<_>,
+            &$left,
+            &$right,
+            approx_eq_rel,
+            $max_relative,
+            "rel"
+        )
+    };
+    ($left:expr, $right:expr, ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::vector::Vector<_>,
+            &$left,
+            &$right,
+            approx_eq_ulps,
+            $max_ulps,
+            "ulps"
+        )
+    };
+}
+
+/// Asserts that two Matrices have the same shape and approximately equal elements.
+///
+/// The tolerance defaults to `ApproxEq::default_epsilon()` and can be given as
+/// `abs = epsilon`, `rel = max_relative` or `ulps = max_ulps`. Both operands are printed
+/// on failure.
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::matrix::Matrix<_>,
+            &$left,
+            &$right,
+            approx_eq_abs,
+            @default,
+            "abs"
+        )
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::matrix::Matrix<_>,
+            &$left,
+            &$right,
+            approx_eq_abs,
+            $epsilon,
+            "abs"
+        )
+    };
+    ($left:expr, $right:expr, rel = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::matrix::Matrix<_>,
+            &$left,
+            &$right,
+            approx_eq_rel,
+            $max_relative,
+            "rel"
+        )
+    };
+    ($left:expr, $right:expr, ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx_eq!(
+            $crate::matrix::Matrix<_>,
+            &$left,
+            &$right,
+            approx_eq_ulps,
+            $max_ulps,
+            "ulps"
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalars() {
+        assert!(1f64.approx_eq_abs(&1.05, 0.1));
+        assert!(!1f64.approx_eq_abs(&1.2, 0.1));
+        assert!(1e9f64.approx_eq_rel(&(1e9 + 1.), 1e-6));
+        assert!(!1e-9f64.approx_eq_rel(&2e-9, 1e-6));
+        assert!(1f32.approx_eq_ulps(&(1. + f32::EPSILON), 1));
+        assert!(!1f32.approx_eq_ulps(&(1. + 2. * f32::EPSILON), 1));
+        assert!(0f64.approx_eq_ulps(&-0., 0));
+        assert!(!f64::NAN.approx_eq_ulps(&f64::NAN, 4));
+        assert!(0.1f64.approx_eq(&(0.3 - 0.2)));
+
+        let a = Complex(num::Complex::new(3f64, 4.));
+        let b = Complex(num::Complex::new(3f64, 4.5));
+        assert!(a.approx_eq_abs(&b, 0.5));
+        assert!(!a.approx_eq_abs(&b, 0.4));
+        assert!(a.approx_eq_rel(&b, 0.1));
+    }
+
+    #[test]
+    fn test_shapes() {
+        let u = Vector::from([1., 2.]);
+        assert!(!u.approx_eq_abs(&Vector::from([1., 2., 3.]), 1.));
+        assert!(u.approx_eq_ulps(&Vector::from([1., 2.]), 0));
+        let m = Matrix::from([[1., 2.], [3., 4.]]);
+        assert!(!m.approx_eq(&Matrix::from([[1., 2., 3., 4.]])));
+        assert!(m.approx_eq_rel(&Matrix::from([[1., 2.], [3., 4. + 1e-9]]), 1e-6));
+    }
+
+    #[test]
+    fn test_macros() {
+        assert_vector_approx_eq!(Vector::from([0.1f64 + 0.2]), Vector::from([0.3]));
+        assert_vector_approx_eq!(Vector::from([1f32]), Vector::from([1.01]), abs = 0.1);
+        assert_matrix_approx_eq!(
+            Matrix::from([[1e6f64, 2e6]]),
+            Matrix::from([[1e6 + 1., 2e6]]),
+            rel = 1e-5
+        );
+        assert_matrix_approx_eq!(
+            Matrix::from([[1f64]]),
+            Matrix::from([[1. + f64::EPSILON]]),
+            ulps = 1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "left ≈ right")]
+    fn test_vector_macro_size_mismatch() {
+        assert_vector_approx_eq!(Vector::from([1f64]), Vector::from([1f64, 0.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "[ 2.0 ]")]
+    fn test_matrix_macro_prints_operands() {
+        assert_matrix_approx_eq!(Matrix::from([[1f64]]), Matrix::from([[2f64]]), abs = 0.5);
+    }
+}
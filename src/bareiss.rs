@@ -0,0 +1,179 @@
+use crate::{
+    error::MatrixError,
+    ex10::{switch_sign, switch_two_rows, FirstNonZero, PivotStrategy},
+    matrix::Matrix,
+    scalar::IntegerScalar,
+};
+
+/// The outcome of a fraction-free elimination.
+struct Elimination<K> {
+    /// The echelon form, whose last pivot is the determinant of the leading minor.
+    data: Matrix<K>,
+    /// The number of pivots found.
+    rank: usize,
+    /// The number of row switches performed.
+    switch_counter: usize,
+}
+
+impl<K: IntegerScalar> Matrix<K> {
+    /// Runs the Bareiss fraction-free elimination on a copy of the matrix.
+    ///
+    /// Every entry computed along the way is a minor of the matrix, so each division is
+    /// exact and the entries never grow past the size of the determinant.
+    ///
+    /// # Returns
+    ///
+    /// The eliminated matrix with its rank and row switch count, or a
+    /// `MatrixError::Overflow` if an intermediate result does not fit in `K`.
+    fn bareiss(&self) -> Result<Elimination<K>, MatrixError> {
+        let mut data = self.clone();
+        let mut switch_counter = 0;
+        let mut previous = K::one();
+        let mut rank = 0;
+        for col in 0..self.cols {
+            if rank == self.rows {
+                break;
+            }
+            let Some(pivot_row) = FirstNonZero::find_pivot(&data, col, rank) else {
+                continue;
+            };
+            switch_two_rows(&mut data, rank, pivot_row, &mut switch_counter);
+            let pivot = data[(rank, col)].clone();
+            for i in rank + 1..self.rows {
+                let factor = data[(i, col)].clone();
+                for j in col + 1..self.cols {
+                    let a = data[(i, j)].checked_mul(&pivot);
+                    let b = factor.checked_mul(&data[(rank, j)]);
+                    data[(i, j)] = a
+                        .zip(b)
+                        .and_then(|(a, b)| a.checked_sub(&b))
+                        .and_then(|x| x.checked_exact_div(&previous))
+                        .ok_or(MatrixError::Overflow)?;
+                }
+                data[(i, col)] = K::zero();
+            }
+            previous = pivot;
+            rank += 1;
+        }
+        Ok(Elimination {
+            data,
+            rank,
+            switch_counter,
+        })
+    }
+
+    /// Calculates the determinant of the matrix without leaving its integer type.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if an intermediate result overflows.
+    pub fn bareiss_determinant(&self) -> K {
+        self.try_bareiss_determinant()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Calculates the determinant of the matrix without leaving its integer type.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the matrix, a `MatrixError::NotSquare` if the matrix is not
+    /// square, or a `MatrixError::Overflow` if an intermediate result does not fit in `K`.
+    pub fn try_bareiss_determinant(&self) -> Result<K, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        if self.rows == 0 {
+            return Ok(K::one());
+        }
+        let elimination = self.bareiss()?;
+        if elimination.rank < self.rows {
+            return Ok(K::zero());
+        }
+        let n = self.rows - 1;
+        Ok(switch_sign(
+            elimination.data[(n, n)].clone(),
+            elimination.switch_counter,
+        ))
+    }
+
+    /// Calculates the rank of the matrix without leaving its integer type.
+    ///
+    /// # Returns
+    ///
+    /// The rank of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an intermediate result overflows.
+    pub fn bareiss_rank(&self) -> usize {
+        self.try_bareiss_rank().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Calculates the rank of the matrix without leaving its integer type.
+    ///
+    /// # Returns
+    ///
+    /// The rank of the matrix, or a `MatrixError::Overflow` if an intermediate result
+    /// does not fit in `K`.
+    pub fn try_bareiss_rank(&self) -> Result<usize, MatrixError> {
+        Ok(self.bareiss()?.rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::bigint::BigInt;
+
+    #[test]
+    fn test_bareiss_determinant() {
+        let u = Matrix::from([[1i64, -1], [-1, 1]]);
+        assert_eq!(u.bareiss_determinant(), 0);
+        let u = Matrix::from([[8i64, 5, -2], [4, 7, 20], [7, 6, 1]]);
+        assert_eq!(u.bareiss_determinant(), -174);
+        let u = Matrix::from([[0i32, 2, 1], [3, 0, 0], [0, 1, 4]]);
+        assert_eq!(u.bareiss_determinant(), -21);
+        let u = Matrix::from([
+            [16i64, 10, -4, 8],
+            [8, 5, 40, 8],
+            [16, 10, 2, 8],
+            [56, -8, 34, 2],
+        ]);
+        assert_eq!(u.bareiss_determinant(), 1032 * 16);
+    }
+
+    #[test]
+    fn test_try_bareiss_determinant() {
+        let u = Matrix::from([[1i64, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            u.try_bareiss_determinant(),
+            Err(MatrixError::NotSquare { shape: [3, 2] })
+        );
+        let big = i64::MAX / 2;
+        let u = Matrix::from([[big, 1], [-1, big]]);
+        assert_eq!(u.try_bareiss_determinant(), Err(MatrixError::Overflow));
+        let u = Matrix::from_fn(2, 2, |i, j| BigInt::from(u[(i, j)]));
+        assert_eq!(
+            u.bareiss_determinant(),
+            BigInt::from(big) * BigInt::from(big) + BigInt::from(1)
+        );
+    }
+
+    #[test]
+    fn test_bareiss_rank() {
+        let u = Matrix::from([[1i64, 2, 0, 0], [2, 4, 0, 0], [-1, 2, 1, 1]]);
+        assert_eq!(u.bareiss_rank(), 2);
+        let u = Matrix::from([[0i64, 0, 3], [0, 0, 6], [1, 0, 0]]);
+        assert_eq!(u.bareiss_rank(), 2);
+        let u = Matrix::from([[8i64, 5, -2], [4, 7, 20], [7, 6, 1], [21, 18, 7]]);
+        assert_eq!(u.bareiss_rank(), 3);
+        let u = Matrix::from_fn(6, 6, |i, j| BigInt::from(i * 6 + j));
+        assert_eq!(u.bareiss_rank(), 2);
+    }
+}
@@ -0,0 +1,568 @@
+use std::cmp::Ordering;
+
+use num_traits::{Float, Zero};
+
+use crate::{error::MatrixError, matrix::Matrix, scalar::ComplexField, vector::Vector};
+
+/// The Cholesky factorization `A = L * Lᴴ` of a Hermitian positive definite matrix `A`.
+#[derive(Clone, Debug)]
+pub struct Cholesky<K> {
+    /// The lower triangular factor, with a real and positive diagonal.
+    pub l: Matrix<K>,
+}
+
+/// The factorization `P * A * Pᵀ = L * D * Lᴴ` of a Hermitian matrix `A`, which need not
+/// be positive definite.
+#[derive(Clone, Debug)]
+pub struct Ldlt<K> {
+    /// The unit lower triangular factor.
+    pub l: Matrix<K>,
+    /// The Hermitian block diagonal factor, made of 1x1 and 2x2 blocks. A 2x2 block starts
+    /// at `k` when `d[(k + 1, k)]` is not zero.
+    pub d: Matrix<K>,
+    /// The symmetric permutation: row `i` of `P * A` is row `perm[i]` of `A`.
+    pub perm: Vec<usize>,
+    /// The largest modulus of a 1x1 block of `D` that counts as zero, the default tolerance
+    /// of `A`.
+    pub tolerance: f64,
+}
+
+/// The Bunch-Kaufman threshold `(1 + √17) / 8`, which bounds the growth of the entries of
+/// `L` by choosing between 1x1 and 2x2 pivots.
+const BUNCH_KAUFMAN_ALPHA: f64 = 0.6403882032022076;
+
+impl<K: ComplexField> Matrix<K> {
+    /// Computes the Cholesky factorization of a Hermitian positive definite matrix.
+    ///
+    /// Only the lower triangle of the matrix is read, the upper one being assumed to be
+    /// its conjugate transpose. Failing is the expected way to find out that a Hermitian
+    /// matrix is not positive definite.
+    ///
+    /// # Returns
+    ///
+    /// The Cholesky factorization of the matrix, a `MatrixError::NotSquare` if the matrix
+    /// is not square, or a `MatrixError::NotPositiveDefinite` if it is not positive
+    /// definite.
+    pub fn cholesky(&self) -> Result<Cholesky<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let n = self.rows;
+        let mut l: Matrix<K> = Matrix::zeros(n, n);
+        for j in 0..n {
+            let mut pivot = self[(j, j)].clone();
+            for k in 0..j {
+                pivot -= l[(j, k)].clone() * l[(j, k)].conjugate();
+            }
+            let pivot = pivot.real();
+            if pivot.is_nan() || pivot <= K::Real::zero() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let pivot = K::from_real(pivot.sqrt());
+            for i in j + 1..n {
+                let mut value = self[(i, j)].clone();
+                for k in 0..j {
+                    value -= l[(i, k)].clone() * l[(j, k)].conjugate();
+                }
+                l[(i, j)] = value / pivot.clone();
+            }
+            l[(j, j)] = pivot;
+        }
+        Ok(Cholesky { l })
+    }
+
+    /// Computes the LDLᴴ factorization of a Hermitian matrix with Bunch-Kaufman symmetric
+    /// pivoting.
+    ///
+    /// Only the lower triangle of the matrix is read, the upper one being assumed to be
+    /// its conjugate transpose. Each step eliminates either one column with a 1x1 pivot or
+    /// two columns with a 2x2 pivot, whichever keeps the entries of `L` bounded, so every
+    /// Hermitian matrix can be factored, including indefinite and singular ones. A column
+    /// whose entries are all negligible is kept as a zero pivot in `D`.
+    ///
+    /// # Returns
+    ///
+    /// The LDLᴴ factorization of the matrix, or a `MatrixError::NotSquare` if the matrix is
+    /// not square.
+    pub fn ldlt(&self) -> Result<Ldlt<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let n = self.rows;
+        let tolerance = self.default_tolerance();
+        let alpha: K::Real = num_traits::cast(BUNCH_KAUFMAN_ALPHA).unwrap_or_else(K::Real::nan);
+        let mut a = Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            Ordering::Greater => self[(i, j)].clone(),
+            Ordering::Less => self[(j, i)].conjugate(),
+            Ordering::Equal => K::from_real(self[(i, i)].real()),
+        });
+        let mut l: Matrix<K> = Matrix::identity(n);
+        let mut d: Matrix<K> = Matrix::zeros(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut k = 0;
+        while k < n {
+            let diagonal = a[(k, k)].modulus();
+            let (r, lambda) = (k + 1..n).map(|i| (i, a[(i, k)].modulus())).fold(
+                (k, K::Real::zero()),
+                |max, x| if x.1 > max.1 { x } else { max },
+            );
+            if K::from_real(diagonal.max(lambda)).is_negligible(tolerance) {
+                d[(k, k)] = a[(k, k)].clone();
+                k += 1;
+                continue;
+            }
+            let (p, size) = if diagonal >= alpha * lambda {
+                (k, 1)
+            } else {
+                let sigma = (k..n)
+                    .filter(|&i| i != r)
+                    .map(|i| a[(i, r)].modulus())
+                    .fold(K::Real::zero(), K::Real::max);
+                if diagonal * sigma >= alpha * lambda * lambda {
+                    (k, 1)
+                } else if a[(r, r)].modulus() >= alpha * sigma {
+                    (r, 1)
+                } else {
+                    (r, 2)
+                }
+            };
+            let q = k + size - 1;
+            if p != q {
+                a.swap_rows(p, q);
+                a.swap_cols(p, q);
+                perm.swap(p, q);
+                for j in 0..k {
+                    let tmp = l[(p, j)].clone();
+                    l[(p, j)] = l[(q, j)].clone();
+                    l[(q, j)] = tmp;
+                }
+            }
+            if size == 1 {
+                let pivot = a[(k, k)].clone();
+                for i in k + 1..n {
+                    l[(i, k)] = a[(i, k)].clone() / pivot.clone();
+                }
+                for i in k + 1..n {
+                    for j in k + 1..n {
+                        let value = l[(i, k)].clone() * a[(k, j)].clone();
+                        a[(i, j)] -= value;
+                    }
+                }
+                d[(k, k)] = pivot;
+            } else {
+                let (a11, a21, a22) = (
+                    a[(k, k)].clone(),
+                    a[(k + 1, k)].clone(),
+                    a[(k + 1, k + 1)].clone(),
+                );
+                let det = a11.clone() * a22.clone() - a21.clone() * a21.conjugate();
+                for i in k + 2..n {
+                    let (c1, c2) = (a[(i, k)].clone(), a[(i, k + 1)].clone());
+                    l[(i, k)] = (c1.clone() * a22.clone() - c2.clone() * a21.clone()) / det.clone();
+                    l[(i, k + 1)] = (c2 * a11.clone() - c1 * a21.conjugate()) / det.clone();
+                }
+                for i in k + 2..n {
+                    for j in k + 2..n {
+                        let value = l[(i, k)].clone() * a[(k, j)].clone()
+                            + l[(i, k + 1)].clone() * a[(k + 1, j)].clone();
+                        a[(i, j)] -= value;
+                    }
+                }
+                d[(k, k)] = a11;
+                d[(k + 1, k)] = a21.clone();
+                d[(k, k + 1)] = a21.conjugate();
+                d[(k + 1, k + 1)] = a22;
+            }
+            k += size;
+        }
+        Ok(Ldlt {
+            l,
+            d,
+            perm,
+            tolerance,
+        })
+    }
+}
+
+impl<K: ComplexField> Cholesky<K> {
+    /// Computes the determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the factored matrix, which is real and positive.
+    pub fn determinant(&self) -> K {
+        (0..self.l.rows).fold(K::one(), |res, i| {
+            res * self.l[(i, i)].clone() * self.l[(i, i)].clone()
+        })
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `b` differs from the number of rows of `A`.
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system, or a `MatrixError::ShapeMismatch` if the size of `b`
+    /// differs from the number of rows of `A`.
+    pub fn try_solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        let n = self.l.rows;
+        if b.size() != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, n],
+                found: b.shape(),
+            });
+        }
+        let mut x = b.clone();
+        for i in 0..n {
+            for j in 0..i {
+                let value = self.l[(i, j)].clone() * x[j].clone();
+                x[i] -= value;
+            }
+            x[i] /= self.l[(i, i)].clone();
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let value = self.l[(j, i)].conjugate() * x[j].clone();
+                x[i] -= value;
+            }
+            x[i] /= self.l[(i, i)].clone();
+        }
+        Ok(x)
+    }
+
+    /// Computes the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the factored matrix.
+    pub fn inverse(&self) -> Matrix<K> {
+        let n = self.l.rows;
+        let cols: Vec<Vector<K>> = (0..n).map(|j| self.solve(&Vector::basis(j, n))).collect();
+        Matrix::from_fn(n, n, |i, j| cols[j][i].clone())
+    }
+}
+
+impl<K: ComplexField> Ldlt<K> {
+    /// Returns the size of the block of `D` starting at row `k`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The first row of the block.
+    ///
+    /// # Returns
+    ///
+    /// Two if a 2x2 block starts at `k`, one otherwise.
+    fn block_size(&self, k: usize) -> usize {
+        if k + 1 < self.d.rows && !self.d[(k + 1, k)].is_zero() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Computes the determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the factored matrix, which is real.
+    pub fn determinant(&self) -> K {
+        let mut res = K::one();
+        let mut k = 0;
+        while k < self.d.rows {
+            if self.block_size(k) == 2 {
+                res *= self.d[(k, k)].clone() * self.d[(k + 1, k + 1)].clone()
+                    - self.d[(k + 1, k)].clone() * self.d[(k, k + 1)].clone();
+                k += 2;
+            } else {
+                res *= self.d[(k, k)].clone();
+                k += 1;
+            }
+        }
+        res
+    }
+
+    /// Checks if the factored matrix is singular.
+    ///
+    /// The 2x2 blocks of `D` are chosen nonsingular, so only the 1x1 blocks are checked.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `D` has a 1x1 block no larger than `tolerance`, `false` otherwise.
+    pub fn is_singular(&self) -> bool {
+        let mut k = 0;
+        while k < self.d.rows {
+            let size = self.block_size(k);
+            if size == 1 && self.d[(k, k)].is_negligible(self.tolerance) {
+                return true;
+            }
+            k += size;
+        }
+        false
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `b` differs from the number of rows of `A` or if `A` is
+    /// singular.
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system, a `MatrixError::ShapeMismatch` if the size of `b`
+    /// differs from the number of rows of `A`, or a `MatrixError::Singular` if `A` is
+    /// singular.
+    pub fn try_solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        let n = self.l.rows;
+        if b.size() != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, n],
+                found: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+        let mut x: Vector<K> = self.perm.iter().map(|&p| b[p].clone()).collect();
+        for i in 0..n {
+            for j in 0..i {
+                let value = self.l[(i, j)].clone() * x[j].clone();
+                x[i] -= value;
+            }
+        }
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 2 {
+                let (d11, d21, d12, d22) = (
+                    self.d[(k, k)].clone(),
+                    self.d[(k + 1, k)].clone(),
+                    self.d[(k, k + 1)].clone(),
+                    self.d[(k + 1, k + 1)].clone(),
+                );
+                let det = d11.clone() * d22.clone() - d21.clone() * d12.clone();
+                let (y1, y2) = (x[k].clone(), x[k + 1].clone());
+                x[k] = (d22 * y1.clone() - d12 * y2.clone()) / det.clone();
+                x[k + 1] = (d11 * y2 - d21 * y1) / det;
+                k += 2;
+            } else {
+                x[k] /= self.d[(k, k)].clone();
+                k += 1;
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let value = self.l[(j, i)].conjugate() * x[j].clone();
+                x[i] -= value;
+            }
+        }
+        let mut res = Vector::zeros(n);
+        for (i, &p) in self.perm.iter().enumerate() {
+            res[p] = x[i].clone();
+        }
+        Ok(res)
+    }
+
+    /// Computes the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the factored matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the factored matrix is singular.
+    pub fn inverse(&self) -> Matrix<K> {
+        self.try_inverse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the factored matrix, or a `MatrixError::Singular` if it is singular.
+    pub fn try_inverse(&self) -> Result<Matrix<K>, MatrixError> {
+        let n = self.l.rows;
+        let cols = (0..n)
+            .map(|j| self.try_solve(&Vector::basis(j, n)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Matrix::from_fn(n, n, |i, j| cols[j][i].clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        approx::ApproxEq, assert_matrix_approx_eq, assert_vector_approx_eq,
+        decomposition::test_utils::permutation, Complex,
+    };
+
+    #[test]
+    fn test_cholesky() {
+        let a = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let cholesky = a.cholesky().unwrap();
+        assert_eq!(
+            cholesky.l,
+            Matrix::from([[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]])
+        );
+        assert_eq!(cholesky.determinant(), 36.);
+        let b = Vector::from([1., 2., 3.]);
+        assert_vector_approx_eq!(&a * &cholesky.solve(&b), b);
+        assert_matrix_approx_eq!(&a * &cholesky.inverse(), Matrix::identity(3));
+        let empty = Matrix::<f64>::zeros(0, 0).cholesky().unwrap();
+        assert_eq!(empty.inverse().shape(), [0, 0]);
+        assert_eq!(
+            cholesky.try_solve(&Vector::from([1., 2.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let a = Matrix::from([[1., 2.], [2., 1.]]);
+        assert_eq!(a.cholesky().err(), Some(MatrixError::NotPositiveDefinite));
+        let a = Matrix::from([[1., 1.], [1., 1.]]);
+        assert_eq!(a.cholesky().err(), Some(MatrixError::NotPositiveDefinite));
+        let a = Matrix::from([[f64::NAN]]);
+        assert_eq!(a.cholesky().err(), Some(MatrixError::NotPositiveDefinite));
+        let a = Matrix::from([[1., 2., 3.]]);
+        assert_eq!(
+            a.cholesky().err(),
+            Some(MatrixError::NotSquare { shape: [3, 1] })
+        );
+    }
+
+    #[test]
+    fn test_cholesky_complex() {
+        let c = |re, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([[c(2., 0.), c(1., -1.)], [c(1., 1.), c(3., 0.)]]);
+        let cholesky = a.cholesky().unwrap();
+        assert_matrix_approx_eq!(&cholesky.l * &cholesky.l.transpose(), a);
+        assert_eq!(cholesky.l[(0, 1)], c(0., 0.));
+        assert!((cholesky.determinant() - c(4., 0.)).0.norm() < 1e-12);
+        let b = Vector::from([c(1., 0.), c(0., 1.)]);
+        assert_vector_approx_eq!(&a * &cholesky.solve(&b), b);
+    }
+
+    #[test]
+    fn test_ldlt() {
+        let a = Matrix::from([[1., 2.], [2., 1.]]);
+        let ldlt = a.ldlt().unwrap();
+        assert_eq!(ldlt.l, Matrix::identity(2));
+        assert_eq!(ldlt.d, a);
+        assert_eq!(ldlt.perm, vec![0, 1]);
+        assert_eq!(ldlt.determinant(), -3.);
+        let b = Vector::from([3., 3.]);
+        assert_vector_approx_eq!(ldlt.solve(&b), Vector::from([1., 1.]));
+        assert_matrix_approx_eq!(&a * &ldlt.inverse(), Matrix::identity(2));
+        let a = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let ldlt = a.ldlt().unwrap();
+        let p = permutation(&ldlt.perm);
+        assert_matrix_approx_eq!(
+            &(&p * &a) * &p.transpose(),
+            &(&ldlt.l * &ldlt.d) * &ldlt.l.transpose()
+        );
+        assert!(ldlt
+            .determinant()
+            .approx_eq(&a.cholesky().unwrap().determinant()));
+        let empty = Matrix::<f64>::zeros(0, 0).ldlt().unwrap();
+        assert_eq!(empty.inverse().shape(), [0, 0]);
+    }
+
+    #[test]
+    fn test_ldlt_pivoting() {
+        let a = Matrix::from([
+            [0., 1., 2., 3.],
+            [1., 0., 4., 5.],
+            [2., 4., 0., 6.],
+            [3., 5., 6., 1e-3],
+        ]);
+        let ldlt = a.ldlt().unwrap();
+        let p = permutation(&ldlt.perm);
+        assert_matrix_approx_eq!(
+            &(&p * &a) * &p.transpose(),
+            &(&ldlt.l * &ldlt.d) * &ldlt.l.transpose()
+        );
+        assert_eq!(ldlt.perm, vec![0, 3, 2, 1]);
+        assert_eq!(ldlt.d[(1, 0)], 3.);
+        assert_matrix_approx_eq!(ldlt.d, ldlt.d.transpose());
+        assert!(ldlt.determinant().approx_eq(&a.determinant()));
+        let b = Vector::from([1., 2., 3., 4.]);
+        assert_vector_approx_eq!(&a * &ldlt.solve(&b), b);
+        assert_matrix_approx_eq!(&a * &ldlt.inverse(), Matrix::identity(4));
+    }
+
+    #[test]
+    fn test_ldlt_complex() {
+        let c = |re, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(0., 0.), c(1., -1.), c(2., 0.)],
+            [c(1., 1.), c(0., 0.), c(0., 3.)],
+            [c(2., 0.), c(0., -3.), c(1., 0.)],
+        ]);
+        let ldlt = a.ldlt().unwrap();
+        assert_matrix_approx_eq!(&a * &ldlt.inverse(), Matrix::identity(3));
+        let b = Vector::from([c(1., 0.), c(0., 1.), c(1., 1.)]);
+        assert_vector_approx_eq!(&a * &ldlt.solve(&b), b);
+        assert!(ldlt.determinant().approx_eq(&a.determinant()));
+    }
+
+    #[test]
+    fn test_ldlt_singular() {
+        let a = Matrix::from([[1., 1.], [1., 1.]]);
+        let ldlt = a.ldlt().unwrap();
+        assert!(ldlt.is_singular());
+        assert_eq!(ldlt.determinant(), 0.);
+        assert_eq!(ldlt.try_inverse(), Err(MatrixError::Singular));
+        let a = Matrix::from([[0., 1.], [1., 0.]]);
+        let ldlt = a.ldlt().unwrap();
+        assert!(!ldlt.is_singular());
+        assert_eq!(ldlt.determinant(), -1.);
+        assert_vector_approx_eq!(ldlt.solve(&Vector::from([2., 3.])), Vector::from([3., 2.]));
+        let a = Matrix::from([[0., 0.], [0., 0.]]);
+        assert!(a.ldlt().unwrap().is_singular());
+    }
+}
@@ -0,0 +1,774 @@
+use num_traits::{Float, NumCast, One, Zero};
+
+use super::{hessenberg::Hessenberg, svd::rotate};
+use crate::{
+    error::MatrixError, ex04::Modulus, matrix::Matrix, scalar::ComplexField, vector::Vector,
+    Complex,
+};
+
+/// The number of Jacobi sweeps `symmetric_eigen` allows before giving up.
+const MAX_SWEEPS: usize = 64;
+
+/// The eigendecomposition `A = V * Λ * Vᴴ` of a real symmetric or complex Hermitian
+/// matrix `A`.
+#[derive(Clone, Debug)]
+pub struct SymmetricEigen<K: Modulus> {
+    /// The diagonal of `Λ`, which is real and sorted in non-decreasing order.
+    pub eigenvalues: Vector<K::Real>,
+    /// The orthonormal eigenvectors, as the columns of the unitary matrix `V`.
+    pub eigenvectors: Matrix<K>,
+}
+
+impl<K: ComplexField> Matrix<K> {
+    /// Computes the eigenvalues and eigenvectors of a symmetric, or Hermitian, matrix with
+    /// the cyclic Jacobi method.
+    ///
+    /// The relative tolerance is the machine epsilon scaled by the size of the matrix,
+    /// and at most 64 sweeps are performed.
+    ///
+    /// # Returns
+    ///
+    /// The eigendecomposition of the matrix, or the errors of `symmetric_eigen_with`.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<K>, MatrixError> {
+        let size: K::Real = NumCast::from(self.rows.max(1)).unwrap_or(K::Real::one());
+        self.symmetric_eigen_with(K::Real::epsilon() * size, MAX_SWEEPS)
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a symmetric, or Hermitian, matrix with
+    /// the cyclic Jacobi method.
+    ///
+    /// Each sweep annihilates every off-diagonal entry once with a plane rotation, and
+    /// the iteration stops when the off-diagonal part is negligible.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The relative tolerance, compared to the Frobenius norm of the
+    ///   off-diagonal part divided by the one of the matrix. It also bounds how far the
+    ///   matrix may be from its conjugate transpose.
+    /// * `max_sweeps` - The maximum number of sweeps to perform.
+    ///
+    /// # Returns
+    ///
+    /// The eigendecomposition of the matrix, a `MatrixError::NotSquare` if the matrix is
+    /// not square, a `MatrixError::NotSymmetric` if it differs from its conjugate
+    /// transpose, or a `MatrixError::NoConvergence` if the tolerance is not reached within
+    /// `max_sweeps` sweeps.
+    pub fn symmetric_eigen_with(
+        &self,
+        tolerance: K::Real,
+        max_sweeps: usize,
+    ) -> Result<SymmetricEigen<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let n = self.rows;
+        let threshold = tolerance * self.as_view().norm();
+        for i in 0..n {
+            for j in 0..=i {
+                if (self[(i, j)].clone() - self[(j, i)].conjugate()).modulus() > threshold {
+                    return Err(MatrixError::NotSymmetric);
+                }
+            }
+        }
+        let mut a = self.clone();
+        let mut v: Matrix<K> = Matrix::identity(n);
+        for sweep in 0..=max_sweeps {
+            if off_diagonal_norm(&a) <= threshold {
+                break;
+            }
+            if sweep == max_sweeps {
+                return Err(MatrixError::NoConvergence {
+                    iterations: max_sweeps,
+                });
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    let g = a[(p, q)].modulus();
+                    if g.is_zero() {
+                        continue;
+                    }
+                    let phase = a[(p, q)].clone() / K::from_real(g);
+                    let theta = (a[(q, q)].real() - a[(p, p)].real()) / (g + g);
+                    let t =
+                        theta.signum() / (theta.abs() + (K::Real::one() + theta * theta).sqrt());
+                    let c = (K::Real::one() + t * t).sqrt().recip();
+                    rotate(&mut a, p, q, c, c * t, &phase);
+                    rotate_rows(&mut a, p, q, c, c * t, &phase);
+                    rotate(&mut v, p, q, c, c * t, &phase);
+                    a[(p, q)] = K::zero();
+                    a[(q, p)] = K::zero();
+                }
+            }
+        }
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            a[(i, i)]
+                .real()
+                .partial_cmp(&a[(j, j)].real())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(SymmetricEigen {
+            eigenvalues: order.iter().map(|&i| a[(i, i)].real()).collect(),
+            eigenvectors: Matrix::from_fn(n, n, |i, j| v[(i, order[j])].clone()),
+        })
+    }
+}
+
+/// The number of QR iterations `francis` allows for each eigenvalue before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// The eigenvalues and eigenvectors of a general real matrix.
+#[derive(Clone, Debug)]
+pub struct Eigen {
+    /// The eigenvalues, in the order in which they appear on the real Schur form, complex
+    /// conjugate pairs being adjacent.
+    pub eigenvalues: Vector<Complex<f64>>,
+    /// The eigenvectors, normalized to a unit Euclidean norm, as the columns of a matrix.
+    pub eigenvectors: Matrix<Complex<f64>>,
+}
+
+impl Matrix<f64> {
+    /// Computes the eigenvalues of a square real matrix.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues of the matrix, real or in complex conjugate pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if the QR iteration does not converge.
+    pub fn eigenvalues(&self) -> Vector<Complex<f64>> {
+        self.try_eigenvalues().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the eigenvalues of a square real matrix.
+    ///
+    /// The matrix is reduced to Hessenberg form, and then to real Schur form with the
+    /// Francis double-shift QR iteration, which never leaves real arithmetic.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues of the matrix, a `MatrixError::NotSquare` if the matrix is not
+    /// square, or a `MatrixError::NoConvergence` if the QR iteration does not converge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrix::matrix::Matrix;
+    ///
+    /// let rotation = Matrix::from([[0., -1.], [1., 0.]]);
+    /// let eigenvalues = rotation.try_eigenvalues().unwrap();
+    /// for lambda in eigenvalues.iter() {
+    ///     assert!(lambda.re().abs() < 1e-12);
+    ///     assert!((lambda.im().abs() - 1.).abs() < 1e-12);
+    /// }
+    /// let lambda: num::Complex<f64> = eigenvalues[0].into();
+    /// assert!((lambda.norm() - 1.).abs() < 1e-12);
+    /// ```
+    pub fn try_eigenvalues(&self) -> Result<Vector<Complex<f64>>, MatrixError> {
+        let Hessenberg { mut q, mut h } = self.try_hessenberg()?;
+        francis(&mut h, &mut q)
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a square real matrix.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues and eigenvectors of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if the QR iteration does not converge.
+    pub fn eigen(&self) -> Eigen {
+        self.try_eigen().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a square real matrix.
+    ///
+    /// The eigenvectors of the real Schur form `T` are found by back-substitution, and
+    /// then mapped back by the orthogonal factor `Q` of `A = Q * T * Qᵀ`. A repeated
+    /// eigenvalue yields independent eigenvectors as long as the matrix is not defective.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues and eigenvectors of the matrix, or the errors of
+    /// `try_eigenvalues`.
+    pub fn try_eigen(&self) -> Result<Eigen, MatrixError> {
+        let Hessenberg { mut q, mut h } = self.try_hessenberg()?;
+        let eigenvalues = francis(&mut h, &mut q)?;
+        let n = self.rows;
+        let q = Matrix::from_fn(n, n, |i, j| Complex::from(q[(i, j)]));
+        let mut eigenvectors = &q * &schur_eigenvectors(&h, &eigenvalues);
+        for j in 0..n {
+            let scale = Complex::from(eigenvectors.col(j).to_vector().norm().recip());
+            eigenvectors
+                .col_mut(j)
+                .iter_mut()
+                .for_each(|value| *value *= scale);
+        }
+        Ok(Eigen {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+}
+
+/// Computes the eigenvectors of a matrix in real Schur form by back-substitution.
+///
+/// The eigenvector of the `k`-th eigenvalue is zero below its diagonal block, set to a
+/// null vector of that block shifted by the eigenvalue, and solved for upwards one block
+/// at a time. A shifted diagonal block that is singular, which happens for a repeated
+/// eigenvalue, is perturbed by the machine epsilon scaled by the norm of the matrix.
+///
+/// # Arguments
+///
+/// * `t` - The real Schur form, whose 2x2 diagonal blocks hold complex conjugate pairs.
+/// * `eigenvalues` - The eigenvalues, in the order of the diagonal of `t`.
+///
+/// # Returns
+///
+/// The unnormalized eigenvectors of `t`, as the columns of a matrix.
+fn schur_eigenvectors(t: &Matrix<f64>, eigenvalues: &Vector<Complex<f64>>) -> Matrix<Complex<f64>> {
+    let n = t.rows;
+    let norm = t.iter().fold(0., |res: f64, x| res + x.abs());
+    let small = if norm > 0. {
+        f64::EPSILON * norm
+    } else {
+        f64::MIN_POSITIVE
+    };
+    let perturb = |x: Complex<f64>| {
+        if x.modulus() < small {
+            Complex::from(small)
+        } else {
+            x
+        }
+    };
+    let second = |i: usize| i > 0 && t[(i, i - 1)] != 0.;
+    let entry = |i: usize, j: usize| Complex::from(t[(i, j)]);
+    let mut vectors = Matrix::zeros(n, n);
+    for (k, &lambda) in eigenvalues.iter().enumerate() {
+        let mut x = vec![Complex::from(0.); n];
+        let (top, bottom) = if second(k) {
+            (k - 1, k)
+        } else if k + 1 < n && second(k + 1) {
+            (k, k + 1)
+        } else {
+            (k, k)
+        };
+        if top == bottom {
+            x[k] = Complex::from(1.);
+        } else {
+            x[top] = entry(top, bottom);
+            x[bottom] = lambda - entry(top, top);
+        }
+        let residual = |x: &[Complex<f64>], i: usize| {
+            (i + 1..=bottom).fold(Complex::from(0.), |res, j| res - entry(i, j) * x[j])
+        };
+        let mut i = top;
+        while i > 0 {
+            if second(i - 1) {
+                let p = i - 2;
+                let (a, b) = (entry(p, p) - lambda, entry(p, p + 1));
+                let (c, d) = (entry(p + 1, p), entry(p + 1, p + 1) - lambda);
+                let (r, s) = (residual(&x, p), residual(&x, p + 1));
+                let det = perturb(a * d - b * c);
+                x[p] = (r * d - b * s) / det;
+                x[p + 1] = (a * s - c * r) / det;
+                i = p;
+            } else {
+                let p = i - 1;
+                x[p] = residual(&x, p) / perturb(entry(p, p) - lambda);
+                i = p;
+            }
+        }
+        vectors.col_mut(k).copy_from(&Vector::from(x).as_view());
+    }
+    vectors
+}
+
+/// Reduces an upper Hessenberg matrix to real Schur form with the Francis double-shift
+/// QR iteration.
+///
+/// Each converged 2x2 block with real eigenvalues is split with a rotation, so that only
+/// complex conjugate pairs remain as 2x2 blocks on the diagonal, and every negligible
+/// entry below the diagonal is set to zero.
+///
+/// # Arguments
+///
+/// * `h` - A mutable reference to the Hessenberg matrix, overwritten by its real Schur
+///   form.
+/// * `z` - A mutable reference to a matrix whose columns are multiplied by the same
+///   orthogonal transformations.
+///
+/// # Returns
+///
+/// The eigenvalues of the matrix, or a `MatrixError::NoConvergence` if an eigenvalue does
+/// not converge within `MAX_ITERATIONS` iterations.
+pub(super) fn francis(
+    h: &mut Matrix<f64>,
+    z: &mut Matrix<f64>,
+) -> Result<Vector<Complex<f64>>, MatrixError> {
+    let size = h.rows;
+    let mut eigenvalues = vec![Complex::from(0.); size];
+    if size == 0 {
+        return Ok(Vector::from(eigenvalues));
+    }
+    let eps = f64::EPSILON;
+    let mut norm = 0.;
+    for i in 0..size {
+        for j in i.saturating_sub(1)..size {
+            norm += h[(i, j)].abs();
+        }
+    }
+    let mut n = size - 1;
+    let mut shift = 0.;
+    let mut iterations = 0;
+    loop {
+        let mut l = n;
+        while l > 0 {
+            let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s == 0. {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+        if l > 0 {
+            h[(l, l - 1)] = 0.;
+        }
+        if l == n {
+            h[(n, n)] += shift;
+            eigenvalues[n] = Complex::from(h[(n, n)]);
+            if n == 0 {
+                break;
+            }
+            n -= 1;
+            iterations = 0;
+        } else if l == n - 1 {
+            let w = h[(n, n - 1)] * h[(n - 1, n)];
+            let p = (h[(n - 1, n - 1)] - h[(n, n)]) / 2.;
+            let q = p * p + w;
+            let root = q.abs().sqrt();
+            h[(n, n)] += shift;
+            h[(n - 1, n - 1)] += shift;
+            let x = h[(n, n)];
+            if q >= 0. {
+                let root = if p >= 0. { p + root } else { p - root };
+                eigenvalues[n - 1] = Complex::from(x + root);
+                eigenvalues[n] = if root != 0. {
+                    Complex::from(x - w / root)
+                } else {
+                    eigenvalues[n - 1]
+                };
+                let s = h[(n, n - 1)].abs() + root.abs();
+                let (p, q) = (h[(n, n - 1)] / s, root / s);
+                let r = p.hypot(q);
+                let (p, q) = (p / r, q / r);
+                for j in n - 1..size {
+                    let value = h[(n - 1, j)];
+                    h[(n - 1, j)] = q * value + p * h[(n, j)];
+                    h[(n, j)] = q * h[(n, j)] - p * value;
+                }
+                for i in 0..=n {
+                    let value = h[(i, n - 1)];
+                    h[(i, n - 1)] = q * value + p * h[(i, n)];
+                    h[(i, n)] = q * h[(i, n)] - p * value;
+                }
+                for i in 0..size {
+                    let value = z[(i, n - 1)];
+                    z[(i, n - 1)] = q * value + p * z[(i, n)];
+                    z[(i, n)] = q * z[(i, n)] - p * value;
+                }
+                h[(n, n - 1)] = 0.;
+            } else {
+                eigenvalues[n - 1] = Complex(num::Complex::new(x + p, root));
+                eigenvalues[n] = Complex(num::Complex::new(x + p, -root));
+            }
+            if n < 2 {
+                break;
+            }
+            n -= 2;
+            iterations = 0;
+        } else {
+            if iterations == MAX_ITERATIONS {
+                return Err(MatrixError::NoConvergence { iterations });
+            }
+            let mut x = h[(n, n)];
+            let mut y = h[(n - 1, n - 1)];
+            let mut w = h[(n, n - 1)] * h[(n - 1, n)];
+            if iterations == 10 {
+                shift += x;
+                for i in 0..=n {
+                    h[(i, i)] -= x;
+                }
+                let s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            if iterations == 30 {
+                let s = (y - x) / 2.;
+                let s = s * s + w;
+                if s > 0. {
+                    let s = if y < x { -s.sqrt() } else { s.sqrt() };
+                    let s = x - w / ((y - x) / 2. + s);
+                    for i in 0..=n {
+                        h[(i, i)] -= s;
+                    }
+                    shift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+            iterations += 1;
+            double_shift_step(h, z, l, n, [x, y, w]);
+        }
+    }
+    for j in 0..size {
+        for i in j + 2..size {
+            h[(i, j)] = 0.;
+        }
+    }
+    Ok(Vector::from(eigenvalues))
+}
+
+/// Performs one implicit double-shift QR step on the active block of a Hessenberg
+/// matrix.
+///
+/// # Arguments
+///
+/// * `h` - A mutable reference to the Hessenberg matrix.
+/// * `z` - A mutable reference to the matrix accumulating the transformations.
+/// * `l` - The first row of the active block.
+/// * `n` - The last row of the active block.
+/// * `[x, y, w]` - The trailing diagonal entry, the one before it, and the product of the
+///   two off-diagonal entries of the trailing 2x2 block, which define the shifts.
+fn double_shift_step(
+    h: &mut Matrix<f64>,
+    z: &mut Matrix<f64>,
+    l: usize,
+    n: usize,
+    [x, y, w]: [f64; 3],
+) {
+    let eps = f64::EPSILON;
+    let size = h.rows;
+    let (mut p, mut q, mut r);
+    let mut m = n - 2;
+    loop {
+        let d = h[(m, m)];
+        let (a, b) = (x - d, y - d);
+        p = (a * b - w) / h[(m + 1, m)] + h[(m, m + 1)];
+        q = h[(m + 1, m + 1)] - d - a - b;
+        r = h[(m + 2, m + 1)];
+        let s = p.abs() + q.abs() + r.abs();
+        p /= s;
+        q /= s;
+        r /= s;
+        if m == l {
+            break;
+        }
+        let lhs = h[(m, m - 1)].abs() * (q.abs() + r.abs());
+        let rhs = eps * p.abs() * (h[(m - 1, m - 1)].abs() + d.abs() + h[(m + 1, m + 1)].abs());
+        if lhs < rhs {
+            break;
+        }
+        m -= 1;
+    }
+    for i in m + 2..=n {
+        h[(i, i - 2)] = 0.;
+        if i > m + 2 {
+            h[(i, i - 3)] = 0.;
+        }
+    }
+    for k in m..n {
+        let not_last = k != n - 1;
+        let mut scale = 1.;
+        if k != m {
+            p = h[(k, k - 1)];
+            q = h[(k + 1, k - 1)];
+            r = if not_last { h[(k + 2, k - 1)] } else { 0. };
+            scale = p.abs() + q.abs() + r.abs();
+            if scale == 0. {
+                continue;
+            }
+            p /= scale;
+            q /= scale;
+            r /= scale;
+        }
+        let s = (p * p + q * q + r * r).sqrt().copysign(p);
+        if s == 0. {
+            continue;
+        }
+        if k != m {
+            h[(k, k - 1)] = -s * scale;
+        } else if l != m {
+            h[(k, k - 1)] = -h[(k, k - 1)];
+        }
+        p += s;
+        let (x, y, v) = (p / s, q / s, r / s);
+        q /= p;
+        r /= p;
+        for j in k..size {
+            let mut value = h[(k, j)] + q * h[(k + 1, j)];
+            if not_last {
+                value += r * h[(k + 2, j)];
+                h[(k + 2, j)] -= value * v;
+            }
+            h[(k, j)] -= value * x;
+            h[(k + 1, j)] -= value * y;
+        }
+        for i in 0..=n.min(k + 3) {
+            let mut value = x * h[(i, k)] + y * h[(i, k + 1)];
+            if not_last {
+                value += v * h[(i, k + 2)];
+                h[(i, k + 2)] -= value * r;
+            }
+            h[(i, k)] -= value;
+            h[(i, k + 1)] -= value * q;
+        }
+        for i in 0..size {
+            let mut value = x * z[(i, k)] + y * z[(i, k + 1)];
+            if not_last {
+                value += v * z[(i, k + 2)];
+                z[(i, k + 2)] -= value * r;
+            }
+            z[(i, k)] -= value;
+            z[(i, k + 1)] -= value * q;
+        }
+    }
+}
+
+/// Applies the conjugate transpose of a complex Jacobi rotation to two rows of a matrix.
+///
+/// This is the left-hand counterpart of `rotate`, so that applying both computes
+/// `Wᴴ * A * W`.
+///
+/// # Arguments
+///
+/// * `data` - A mutable reference to the matrix data.
+/// * `p` - The first row.
+/// * `q` - The second row.
+/// * `c` - The cosine of the rotation.
+/// * `s` - The sine of the rotation.
+/// * `phase` - The unit modulus phase of the entry at row `p` and column `q`.
+fn rotate_rows<K: ComplexField>(
+    data: &mut Matrix<K>,
+    p: usize,
+    q: usize,
+    c: K::Real,
+    s: K::Real,
+    phase: &K,
+) {
+    let (c, s) = (K::from_real(c), K::from_real(s));
+    for j in 0..data.cols {
+        let x = data[(p, j)].clone();
+        let y = data[(q, j)].clone() * phase.clone();
+        data[(p, j)] = c.clone() * x.clone() - s.clone() * y.clone();
+        data[(q, j)] = s.clone() * x + c.clone() * y;
+    }
+}
+
+/// Computes the Frobenius norm of the off-diagonal part of a square matrix.
+///
+/// # Arguments
+///
+/// * `data` - The matrix.
+///
+/// # Returns
+///
+/// The square root of the sum of the squared moduli of the off-diagonal entries.
+fn off_diagonal_norm<K: ComplexField>(data: &Matrix<K>) -> K::Real {
+    let mut res = K::Real::zero();
+    for i in 0..data.rows {
+        for j in 0..data.cols {
+            if i != j {
+                let modulus = data[(i, j)].modulus();
+                res = modulus.mul_add(modulus, res);
+            }
+        }
+    }
+    res.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matrix_approx_eq, assert_vector_approx_eq};
+
+    fn check<K: ComplexField + crate::approx::ApproxEq>(a: &Matrix<K>, eigen: &SymmetricEigen<K>)
+    where
+        Matrix<K>: crate::approx::ApproxEq,
+    {
+        let v = &eigen.eigenvectors;
+        let n = a.shape()[0];
+        assert_matrix_approx_eq!(&v.transpose() * v, Matrix::identity(n));
+        let lambda: Vector<K> = eigen.eigenvalues.iter().map(|&x| K::from_real(x)).collect();
+        assert_matrix_approx_eq!(&(v * &Matrix::from_diagonal(&lambda)) * &v.transpose(), a);
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = Matrix::from([[2f64, 1.], [1., 2.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert_vector_approx_eq!(eigen.eigenvalues, Vector::from([1., 3.]));
+        check(&a, &eigen);
+        let a = Matrix::from([[2f64, -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        let r = 2f64.sqrt();
+        assert_vector_approx_eq!(eigen.eigenvalues, Vector::from([2. - r, 2., 2. + r]));
+        check(&a, &eigen);
+        let a = Matrix::from([
+            [4f64, 1., -2., 2.],
+            [1., 2., 0., 1.],
+            [-2., 0., 3., -2.],
+            [2., 1., -2., -1.],
+        ]);
+        let eigen = a.symmetric_eigen().unwrap();
+        check(&a, &eigen);
+        let trace = eigen.eigenvalues.iter().sum::<f64>();
+        assert!((trace - 8.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([[c(2., 0.), c(1., -1.)], [c(1., 1.), c(3., 0.)]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert_vector_approx_eq!(eigen.eigenvalues, Vector::from([1., 4.]));
+        check(&a, &eigen);
+        let a = Matrix::from([
+            [c(1., 0.), c(0., 2.), c(3., -1.)],
+            [c(0., -2.), c(-1., 0.), c(0., 1.)],
+            [c(3., 1.), c(0., -1.), c(2., 0.)],
+        ]);
+        check(&a, &a.symmetric_eigen().unwrap());
+    }
+
+    #[test]
+    fn test_symmetric_eigen_errors() {
+        let a = Matrix::from([[1f64, 2.], [3., 4.]]);
+        assert_eq!(a.symmetric_eigen().err(), Some(MatrixError::NotSymmetric));
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([[c(2., 0.), c(1., 1.)], [c(1., 1.), c(3., 0.)]]);
+        assert_eq!(a.symmetric_eigen().err(), Some(MatrixError::NotSymmetric));
+        let a = Matrix::from([[1f64, 2., 3.]]);
+        assert_eq!(
+            a.symmetric_eigen().err(),
+            Some(MatrixError::NotSquare { shape: [3, 1] })
+        );
+        let a = Matrix::from([[2f64, 1.], [1., 2.]]);
+        assert_eq!(
+            a.symmetric_eigen_with(1e-12, 0).err(),
+            Some(MatrixError::NoConvergence { iterations: 0 })
+        );
+        let a = Matrix::from([[3f64, 0.], [0., -1.]]);
+        let eigen = a.symmetric_eigen_with(1e-12, 0).unwrap();
+        assert_eq!(eigen.eigenvalues, Vector::from([-1., 3.]));
+        assert_eq!(eigen.eigenvectors, Matrix::from([[0., 1.], [1., 0.]]));
+    }
+
+    fn sorted(values: &Vector<Complex<f64>>) -> Vec<(f64, f64)> {
+        let mut values: Vec<(f64, f64)> = values.iter().map(|x| (x.0.re, x.0.im)).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values
+    }
+
+    fn assert_close(values: &Vector<Complex<f64>>, expected: &[(f64, f64)]) {
+        let values = sorted(values);
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value.0 - expected.0).abs() < 1e-10 && (value.1 - expected.1).abs() < 1e-10,
+                "{:?} != {:?}",
+                values,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues() {
+        let a = Matrix::from([[0., 1.], [-1., 0.]]);
+        assert_close(&a.eigenvalues(), &[(0., -1.), (0., 1.)]);
+        let a = Matrix::from([[2., 0.], [0., 3.]]);
+        assert_close(&a.eigenvalues(), &[(2., 0.), (3., 0.)]);
+        let a = Matrix::from([[6., -11., 6.], [1., 0., 0.], [0., 1., 0.]]);
+        assert_close(&a.eigenvalues(), &[(1., 0.), (2., 0.), (3., 0.)]);
+        let a = Matrix::from([[1., 2., 0.], [-2., 1., 0.], [0., 0., 3.]]);
+        assert_close(&a.eigenvalues(), &[(1., -2.), (1., 2.), (3., 0.)]);
+        let a = Matrix::from([[1., 1.], [0., 1.]]);
+        assert_close(&a.eigenvalues(), &[(1., 0.), (1., 0.)]);
+        assert_eq!(Matrix::<f64>::zeros(0, 0).eigenvalues().size(), 0);
+        assert_eq!(
+            Matrix::from([[1., 2.]]).try_eigenvalues().err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_larger() {
+        let a = Matrix::from([
+            [1., 2., 3., 4., 5.],
+            [-1., 0., 2., 1., 3.],
+            [4., -2., 1., 0., 1.],
+            [0., 1., -3., 2., 2.],
+            [2., 2., 1., -1., 0.],
+        ]);
+        let eigenvalues = a.eigenvalues();
+        let trace = eigenvalues
+            .iter()
+            .fold(Complex::from(0.), |res, &x| res + x);
+        assert!((trace.0.re - 4.).abs() < 1e-10 && trace.0.im.abs() < 1e-10);
+        let product = eigenvalues
+            .iter()
+            .fold(Complex::from(1.), |res, &x| res * x);
+        assert!((product.0.re - a.determinant()).abs() < 1e-8 && product.0.im.abs() < 1e-8);
+        let a = Matrix::from_fn(8, 8, |i, j| if j == (i + 1) % 8 { 1. } else { 0. });
+        for lambda in a.eigenvalues().iter() {
+            assert!((lambda.0.norm() - 1.).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_eigen() {
+        let a = Matrix::from([
+            [1., 2., 0., 1.],
+            [-2., 1., 0., 0.],
+            [0., 1., 3., 1.],
+            [1., 0., 0., 2.],
+        ]);
+        let eigen = a.eigen();
+        let a = Matrix::from_fn(4, 4, |i, j| Complex::from(a[(i, j)]));
+        for (j, &lambda) in eigen.eigenvalues.iter().enumerate() {
+            let v = eigen.eigenvectors.col(j).to_vector();
+            assert!((v.norm() - 1.).abs() < 1e-12);
+            let mut expected = v.clone();
+            expected.iter_mut().for_each(|x| *x *= lambda);
+            assert_vector_approx_eq!(&a * &v, expected, abs = 1e-9);
+        }
+        let eigen = Matrix::<f64>::identity(2).eigen();
+        assert_matrix_approx_eq!(eigen.eigenvectors, Matrix::identity(2));
+        let a = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 3.]]);
+        let eigen = a.eigen();
+        assert_close(&eigen.eigenvalues, &[(2., 0.), (2., 0.), (3., 0.)]);
+        assert_matrix_approx_eq!(eigen.eigenvectors, Matrix::identity(3));
+        for a in [
+            Matrix::from([[1., 2., 0.], [-2., 1., 1.], [0., 0., 3.]]),
+            Matrix::from([[3., 1., 0.], [0., 3., 0.], [1., 0., 3.]]),
+        ] {
+            let eigen = a.eigen();
+            let a = Matrix::from_fn(3, 3, |i, j| Complex::from(a[(i, j)]));
+            for (j, &lambda) in eigen.eigenvalues.iter().enumerate() {
+                let v = eigen.eigenvectors.col(j).to_vector();
+                let mut expected = v.clone();
+                expected.iter_mut().for_each(|x| *x *= lambda);
+                assert_vector_approx_eq!(&a * &v, expected, abs = 1e-6);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::qr::{reflect, reflector};
+use crate::{error::MatrixError, matrix::Matrix, scalar::ComplexField, vector::Vector};
+
+/// The Hessenberg decomposition `A = Q * H * Qᴴ` of a square matrix `A`.
+#[derive(Clone, Debug)]
+pub struct Hessenberg<K> {
+    /// The orthogonal, or unitary, factor.
+    pub q: Matrix<K>,
+    /// The upper Hessenberg factor, which is zero below its first subdiagonal.
+    pub h: Matrix<K>,
+}
+
+impl<K: ComplexField> Matrix<K> {
+    /// Reduces a square matrix to upper Hessenberg form with Householder reflections.
+    ///
+    /// # Returns
+    ///
+    /// The Hessenberg decomposition of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn hessenberg(&self) -> Hessenberg<K> {
+        self.try_hessenberg().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reduces a square matrix to upper Hessenberg form with Householder reflections.
+    ///
+    /// # Returns
+    ///
+    /// The Hessenberg decomposition of the matrix, or a `MatrixError::NotSquare` if the
+    /// matrix is not square.
+    pub fn try_hessenberg(&self) -> Result<Hessenberg<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let n = self.rows;
+        let mut h = self.clone();
+        let mut q: Matrix<K> = Matrix::identity(n);
+        for k in 0..n.saturating_sub(2) {
+            let v = reflector(&h.block(k + 1, k, n - k - 1, 1).to_vector());
+            reflect(&v, &mut h, k + 1);
+            reflect_right(&v, &mut h, k + 1);
+            reflect_right(&v, &mut q, k + 1);
+            for i in k + 2..n {
+                h[(i, k)] = K::zero();
+            }
+        }
+        Ok(Hessenberg { q, h })
+    }
+}
+
+/// Multiplies the columns `k..` of a matrix by a Householder reflection on the right.
+///
+/// # Arguments
+///
+/// * `v` - The Householder vector, with `n - k` elements.
+/// * `data` - A mutable reference to the matrix data.
+/// * `k` - The first column the reflection acts on.
+fn reflect_right<K: ComplexField>(v: &Vector<K>, data: &mut Matrix<K>, k: usize) {
+    for i in 0..data.rows {
+        let w = (0..v.size()).fold(K::zero(), |res, j| {
+            data[(i, k + j)].clone().mul_add(v[j].clone(), res)
+        });
+        if w.is_zero() {
+            continue;
+        }
+        for j in 0..v.size() {
+            data[(i, k + j)] -= w.clone() * v[j].conjugate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matrix_approx_eq, Complex};
+
+    #[test]
+    fn test_hessenberg() {
+        let a = Matrix::from([
+            [4., 1., -2., 2.],
+            [1., 2., 0., 1.],
+            [-2., 0., 3., -2.],
+            [2., 1., -2., -1.],
+        ]);
+        let hessenberg = a.hessenberg();
+        let (q, h) = (&hessenberg.q, &hessenberg.h);
+        assert_matrix_approx_eq!(&q.transpose() * q, Matrix::identity(4));
+        assert_matrix_approx_eq!(&(q * h) * &q.transpose(), a);
+        for j in 0..4 {
+            for i in j + 2..4 {
+                assert_eq!(h[(i, j)], 0.);
+            }
+        }
+        assert_eq!(
+            Matrix::from([[1., 2.]]).try_hessenberg().err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+    }
+
+    #[test]
+    fn test_hessenberg_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.), c(0., -1.)],
+            [c(0., 2.), c(1., -1.), c(3., 0.)],
+            [c(1., 0.), c(0., 1.), c(2., 2.)],
+        ]);
+        let hessenberg = a.hessenberg();
+        let (q, h) = (&hessenberg.q, &hessenberg.h);
+        assert_matrix_approx_eq!(&q.transpose() * q, Matrix::identity(3));
+        assert_matrix_approx_eq!(&(q * h) * &q.transpose(), a);
+        assert_eq!(h[(2, 0)], c(0., 0.));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    error::MatrixError,
+    ex10::{switch_sign, PivotStrategy},
+    matrix::Matrix,
+    scalar::Field,
+    vector::Vector,
+};
+
+/// The LU factorization `P * A = L * U` of a square matrix `A`.
+#[derive(Clone, Debug)]
+pub struct Lu<K> {
+    /// The unit lower triangular factor.
+    pub l: Matrix<K>,
+    /// The upper triangular factor.
+    pub u: Matrix<K>,
+    /// The row permutation: row `i` of `P * A` is row `perm[i]` of `A`.
+    pub perm: Vec<usize>,
+    /// The determinant of `P`, either one or minus one.
+    pub sign: K,
+    /// The largest modulus of a pivot of `U` that counts as zero, the default tolerance of
+    /// `A`.
+    pub tolerance: f64,
+}
+
+impl<K: Field> Matrix<K> {
+    /// Computes the LU factorization of a square matrix, pivoting with `K::Pivot`.
+    ///
+    /// # Returns
+    ///
+    /// The LU factorization of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn lu(&self) -> Lu<K> {
+        self.try_lu().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the LU factorization of a square matrix, pivoting with `K::Pivot`.
+    ///
+    /// Singular matrices are factored too, with negligible pivots on the diagonal of `U`.
+    ///
+    /// # Returns
+    ///
+    /// The LU factorization of the matrix, or a `MatrixError::NotSquare` if the matrix is
+    /// not square.
+    pub fn try_lu(&self) -> Result<Lu<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let n = self.rows;
+        let tolerance = self.default_tolerance();
+        let mut l: Matrix<K> = Matrix::identity(n);
+        let mut u = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut switch_counter = 0;
+        for k in 0..n {
+            let Some(p) = K::Pivot::find_pivot(&u, k, k) else {
+                continue;
+            };
+            if p != k {
+                u.swap_rows(k, p);
+                perm.swap(k, p);
+                for j in 0..k {
+                    let tmp = l[(k, j)].clone();
+                    l[(k, j)] = l[(p, j)].clone();
+                    l[(p, j)] = tmp;
+                }
+                switch_counter += 1;
+            }
+            let pivot = u[(k, k)].clone();
+            for i in k + 1..n {
+                if u[(i, k)].is_zero() {
+                    continue;
+                }
+                let factor = u[(i, k)].clone() / pivot.clone();
+                for j in k + 1..n {
+                    let value = factor.clone() * u[(k, j)].clone();
+                    u[(i, j)] -= value;
+                }
+                u[(i, k)] = K::zero();
+                l[(i, k)] = factor;
+            }
+        }
+        Ok(Lu {
+            l,
+            u,
+            perm,
+            sign: switch_sign(K::one(), switch_counter),
+            tolerance,
+        })
+    }
+}
+
+impl<K: Field> Lu<K> {
+    /// Computes the determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the factored matrix.
+    pub fn determinant(&self) -> K {
+        (0..self.u.rows).fold(self.sign.clone(), |res, i| res * self.u[(i, i)].clone())
+    }
+
+    /// Checks if the factored matrix is singular.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `U` has a pivot no larger than `tolerance` on its diagonal, `false`
+    /// otherwise.
+    pub fn is_singular(&self) -> bool {
+        (0..self.u.rows).any(|i| self.u[(i, i)].is_negligible(self.tolerance))
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `b` differs from the number of rows of `A` or if `A` is
+    /// singular.
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `A * x = b` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system, a `MatrixError::ShapeMismatch` if the size of `b`
+    /// differs from the number of rows of `A`, or a `MatrixError::Singular` if `A` is
+    /// singular.
+    pub fn try_solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        let n = self.u.rows;
+        if b.size() != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, n],
+                found: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+        let mut x: Vector<K> = self.perm.iter().map(|&p| b[p].clone()).collect();
+        for i in 0..n {
+            for j in 0..i {
+                let value = self.l[(i, j)].clone() * x[j].clone();
+                x[i] -= value;
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let value = self.u[(i, j)].clone() * x[j].clone();
+                x[i] -= value;
+            }
+            x[i] /= self.u[(i, i)].clone();
+        }
+        Ok(x)
+    }
+
+    /// Solves the linear system `A * X = B` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand sides, with as many rows as `A`.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A` or if `A` is singular.
+    pub fn solve_matrix(&self, b: &Matrix<K>) -> Matrix<K> {
+        self.try_solve_matrix(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `A * X = B` using the factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand sides, with as many rows as `A`.
+    ///
+    /// # Returns
+    ///
+    /// The solution of the system, a `MatrixError::ShapeMismatch` if `b` does not have as
+    /// many rows as `A`, or a `MatrixError::Singular` if `A` is singular.
+    pub fn try_solve_matrix(&self, b: &Matrix<K>) -> Result<Matrix<K>, MatrixError> {
+        let n = self.u.rows;
+        if b.shape()[1] != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [b.shape()[0], n],
+                found: b.shape(),
+            });
+        }
+        let cols = b
+            .cols()
+            .map(|col| self.try_solve(&col.to_vector()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if cols.is_empty() {
+            return Ok(Matrix::zeros(n, 0));
+        }
+        Matrix::from_cols(&cols)
+    }
+
+    /// Computes the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the factored matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the factored matrix is singular.
+    pub fn inverse(&self) -> Matrix<K> {
+        self.try_inverse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the factored matrix, or a `MatrixError::Singular` if it is singular.
+    pub fn try_inverse(&self) -> Result<Matrix<K>, MatrixError> {
+        self.try_solve_matrix(&Matrix::identity(self.u.rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decomposition::test_utils::permutation;
+    use num::rational::Ratio;
+
+    #[test]
+    fn test_lu() {
+        let a = Matrix::from([[1., 2., 0.], [3., 4., 4.], [5., 6., 3.]]);
+        let lu = a.lu();
+        assert_eq!(lu.perm, vec![2, 0, 1]);
+        assert_eq!(&permutation(&lu.perm) * &a, &lu.l * &lu.u);
+        for i in 0..3 {
+            assert_eq!(lu.l[(i, i)], 1.);
+            for j in i + 1..3 {
+                assert_eq!(lu.l[(i, j)], 0.);
+                assert_eq!(lu.u[(j, i)], 0.);
+            }
+        }
+        assert_eq!(lu.determinant(), a.determinant());
+        assert_eq!(
+            Matrix::from([[1., 2.]]).try_lu().err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        let lu = a.lu();
+        let b = Vector::from([1., 2., 3.]);
+        assert_eq!(&a * &lu.solve(&b), b);
+        let b = Matrix::from([[1., 0.], [2., 1.], [3., 0.]]);
+        assert_eq!(&a * &lu.solve_matrix(&b), b);
+        assert_eq!(lu.inverse(), a.inverse());
+        assert_eq!(lu.determinant(), -174.);
+        assert_eq!(
+            lu.try_solve(&Vector::from([1., 2.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let lu = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]).lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.);
+        assert_eq!(lu.try_inverse(), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn test_lu_rational() {
+        let a = Matrix::from_fn(4, 4, |i, j| Ratio::new(1i64, (i + j + 1) as i64));
+        let lu = a.lu();
+        assert_eq!(lu.determinant(), Ratio::new(1, 6048000));
+        assert_eq!(&a * &lu.inverse(), Matrix::identity(4));
+    }
+}
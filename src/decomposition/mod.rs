@@ -0,0 +1,32 @@
+//! Matrix factorizations that can be computed once and reused.
+
+pub mod cholesky;
+pub mod eigen;
+pub mod hessenberg;
+pub mod lu;
+pub mod qr;
+pub mod schur;
+pub mod svd;
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use crate::matrix::Matrix;
+
+    /// Builds the permutation matrix `P` with a one at `(i, perm[i])` in each row `i`.
+    ///
+    /// Row `i` of `P * A` is row `perm[i]` of `A`, and column `j` of `A * P^T` is column
+    /// `perm[j]` of `A`.
+    pub(crate) fn permutation(perm: &[usize]) -> Matrix<f64> {
+        Matrix::from_fn(
+            perm.len(),
+            perm.len(),
+            |i, j| {
+                if perm[i] == j {
+                    1.
+                } else {
+                    0.
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,420 @@
+use num_traits::{Float, NumCast, Zero};
+
+use crate::{error::MatrixError, matrix::Matrix, scalar::ComplexField, vector::Vector};
+
+/// The QR factorization `A * P = Q * R` of a matrix `A`, where `P` permutes the columns.
+///
+/// `Q` is kept in compact form as a product of Householder reflections, and can be made
+/// explicit with `q` or `thin_q`.
+#[derive(Clone, Debug)]
+pub struct Qr<K> {
+    /// The Householder vectors: the `k`-th reflection is `I - v * vᴴ` acting on rows `k..`,
+    /// where `v` has a squared norm of two, or is zero for the identity.
+    pub reflectors: Vec<Vector<K>>,
+    /// The upper triangular factor, with the shape of `A`.
+    pub r: Matrix<K>,
+    /// The column permutation: column `j` of `A * P` is column `perm[j]` of `A`.
+    pub perm: Vec<usize>,
+    /// Whether the columns were pivoted, which makes the diagonal of `R` non-increasing.
+    pub pivoted: bool,
+}
+
+impl<K: ComplexField> Matrix<K> {
+    /// Computes the QR factorization of the matrix with Householder reflections.
+    ///
+    /// # Returns
+    ///
+    /// The QR factorization of the matrix, with an identity permutation.
+    pub fn qr(&self) -> Qr<K> {
+        self.householder(false)
+    }
+
+    /// Computes the QR factorization of the matrix with Householder reflections and
+    /// column pivoting.
+    ///
+    /// The remaining column of largest norm is moved forward at each step, so the diagonal
+    /// of `R` does not increase in modulus and reveals the rank of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The pivoted QR factorization of the matrix.
+    pub fn qr_pivoted(&self) -> Qr<K> {
+        self.householder(true)
+    }
+
+    /// Triangularizes a copy of the matrix with Householder reflections.
+    ///
+    /// # Arguments
+    ///
+    /// * `pivoting` - Whether to move the remaining column of largest norm forward.
+    ///
+    /// # Returns
+    ///
+    /// The QR factorization of the matrix.
+    fn householder(&self, pivoting: bool) -> Qr<K> {
+        let (m, n) = (self.rows, self.cols);
+        let mut r = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut reflectors = Vec::with_capacity(m.min(n));
+        for k in 0..m.min(n) {
+            if pivoting {
+                let norms: Vec<K::Real> = (k..n).map(|j| r.block(k, j, m - k, 1).norm()).collect();
+                let p = (0..norms.len()).fold(0, |p, j| if norms[j] > norms[p] { j } else { p });
+                r.swap_cols(k, k + p);
+                perm.swap(k, k + p);
+            }
+            let v = reflector(&r.block(k, k, m - k, 1).to_vector());
+            reflect(&v, &mut r, k);
+            for i in k + 1..m {
+                r[(i, k)] = K::zero();
+            }
+            reflectors.push(v);
+        }
+        Qr {
+            reflectors,
+            r,
+            perm,
+            pivoted: pivoting,
+        }
+    }
+}
+
+impl<K: ComplexField> Qr<K> {
+    /// Computes the orthogonal, or unitary, factor explicitly.
+    ///
+    /// # Returns
+    ///
+    /// The square matrix `Q`, with as many rows as `A`.
+    pub fn q(&self) -> Matrix<K> {
+        self.apply_q(Matrix::identity(self.r.rows))
+    }
+
+    /// Computes the first `min(m, n)` columns of the orthogonal, or unitary, factor.
+    ///
+    /// # Returns
+    ///
+    /// The matrix `Q₁` such that `A * P = Q₁ * R₁`, with `R₁` given by `thin_r`.
+    pub fn thin_q(&self) -> Matrix<K> {
+        let (m, p) = (self.r.rows, self.reflectors.len());
+        self.apply_q(Matrix::from_fn(m, p, |i, j| {
+            if i == j {
+                K::one()
+            } else {
+                K::zero()
+            }
+        }))
+    }
+
+    /// Returns the first `min(m, n)` rows of the triangular factor.
+    ///
+    /// # Returns
+    ///
+    /// The upper triangular matrix `R₁` such that `A * P = Q₁ * R₁`.
+    pub fn thin_r(&self) -> Matrix<K> {
+        self.r
+            .block(0, 0, self.reflectors.len(), self.r.cols)
+            .to_matrix()
+    }
+
+    /// Computes the numerical rank of the factored matrix.
+    ///
+    /// A diagonal entry of `R` counts as zero when its modulus is below the largest one
+    /// scaled by the machine epsilon and the largest dimension of the matrix. The result
+    /// is only reliable for a pivoted factorization.
+    ///
+    /// # Returns
+    ///
+    /// The number of leading diagonal entries of `R` that are not negligible.
+    pub fn rank(&self) -> usize {
+        let threshold = self.threshold();
+        (0..self.reflectors.len())
+            .take_while(|&k| self.r[(k, k)].modulus() > threshold)
+            .count()
+    }
+
+    /// Solves the linear system `A * x = b` in the least-squares sense.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution minimizing `‖A * x - b‖`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `b` differs from the number of rows of `A`, or if the
+    /// factorization is not pivoted and `A` is rank deficient.
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `A * x = b` in the least-squares sense.
+    ///
+    /// When `A` is rank deficient, the basic solution with a zero on every column that
+    /// was pivoted past the rank is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as `A` has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution minimizing `‖A * x - b‖`, a `MatrixError::ShapeMismatch` if the size
+    /// of `b` differs from the number of rows of `A`, or a `MatrixError::Singular` if the
+    /// factorization is not pivoted and `A` is rank deficient.
+    pub fn try_solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        let (m, n) = (self.r.rows, self.r.cols);
+        if b.size() != m {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, m],
+                found: b.shape(),
+            });
+        }
+        let rank = self.rank();
+        if !self.pivoted && rank < self.reflectors.len() {
+            return Err(MatrixError::Singular);
+        }
+        let mut c = Matrix::from_cols(std::slice::from_ref(b))?;
+        for (k, v) in self.reflectors.iter().enumerate() {
+            reflect(v, &mut c, k);
+        }
+        let mut y: Vector<K> = Vector::zeros(n);
+        for i in (0..rank).rev() {
+            let mut value = c[(i, 0)].clone();
+            for j in i + 1..rank {
+                value -= self.r[(i, j)].clone() * y[j].clone();
+            }
+            y[i] = value / self.r[(i, i)].clone();
+        }
+        let mut x = Vector::zeros(n);
+        for (j, &p) in self.perm.iter().enumerate() {
+            x[p] = y[j].clone();
+        }
+        Ok(x)
+    }
+
+    /// Multiplies a matrix by `Q` on the left.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A matrix with as many rows as `A`.
+    ///
+    /// # Returns
+    ///
+    /// The product `Q * data`.
+    fn apply_q(&self, mut data: Matrix<K>) -> Matrix<K> {
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            reflect(v, &mut data, k);
+        }
+        data
+    }
+
+    /// Returns the modulus under which a diagonal entry of `R` is negligible.
+    ///
+    /// # Returns
+    ///
+    /// The largest modulus on the diagonal of `R`, scaled by the machine epsilon and the
+    /// largest dimension of the matrix.
+    fn threshold(&self) -> K::Real {
+        let largest = (0..self.reflectors.len())
+            .map(|k| self.r[(k, k)].modulus())
+            .fold(K::Real::zero(), Float::max);
+        let size: K::Real = NumCast::from(self.r.rows.max(self.r.cols)).unwrap_or(largest);
+        largest * K::Real::epsilon() * size
+    }
+}
+
+/// Solves the linear system `a * x = b` in the least-squares sense with a pivoted QR
+/// factorization.
+///
+/// # Arguments
+///
+/// * `a` - The matrix of the system, of any shape.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+///
+/// # Returns
+///
+/// The solution minimizing `‖a * x - b‖`, which is the basic solution if `a` is rank
+/// deficient.
+///
+/// # Panics
+///
+/// Panics if the size of `b` differs from the number of rows of `a`.
+pub fn least_squares<K: ComplexField>(a: &Matrix<K>, b: &Vector<K>) -> Vector<K> {
+    try_least_squares(a, b).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solves the linear system `a * x = b` in the least-squares sense with a pivoted QR
+/// factorization.
+///
+/// # Arguments
+///
+/// * `a` - The matrix of the system, of any shape.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+///
+/// # Returns
+///
+/// The solution minimizing `‖a * x - b‖`, which is the basic solution if `a` is rank
+/// deficient, or a `MatrixError::ShapeMismatch` if the size of `b` differs from the
+/// number of rows of `a`.
+pub fn try_least_squares<K: ComplexField>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+) -> Result<Vector<K>, MatrixError> {
+    a.qr_pivoted().try_solve(b)
+}
+
+/// Computes the Householder vector mapping a column onto a multiple of the first basis
+/// vector.
+///
+/// # Arguments
+///
+/// * `x` - The column to reflect.
+///
+/// # Returns
+///
+/// A vector `v` of squared norm two such that `(I - v * vᴴ) * x` is zero past its first
+/// element, or a zero vector if `x` is zero.
+pub(super) fn reflector<K: ComplexField>(x: &Vector<K>) -> Vector<K> {
+    let norm = x.norm();
+    if norm.is_zero() {
+        return Vector::zeros(x.size());
+    }
+    let head = x[0].modulus();
+    let phase = if head.is_zero() {
+        K::one()
+    } else {
+        x[0].clone() / K::from_real(head)
+    };
+    let mut v = x.clone();
+    v[0] += phase * K::from_real(norm);
+    let scale = K::from_real((norm * (norm + head)).sqrt().recip());
+    for value in v.iter_mut() {
+        *value *= scale.clone();
+    }
+    v
+}
+
+/// Applies a Householder reflection to the rows `k..` of a matrix.
+///
+/// # Arguments
+///
+/// * `v` - The Householder vector, with `m - k` elements.
+/// * `data` - A mutable reference to the matrix data.
+/// * `k` - The first row the reflection acts on.
+pub(super) fn reflect<K: ComplexField>(v: &Vector<K>, data: &mut Matrix<K>, k: usize) {
+    for j in 0..data.cols {
+        let w = (0..v.size()).fold(K::zero(), |res, i| {
+            v[i].conjugate().mul_add(data[(k + i, j)].clone(), res)
+        });
+        if w.is_zero() {
+            continue;
+        }
+        for i in 0..v.size() {
+            data[(k + i, j)] -= v[i].clone() * w.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matrix_approx_eq, assert_vector_approx_eq, decomposition::test_utils::permutation,
+        Complex,
+    };
+
+    #[test]
+    fn test_qr() {
+        let a = Matrix::from([[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+        let qr = a.qr();
+        let q = qr.q();
+        assert_matrix_approx_eq!(&q.transpose() * &q, Matrix::identity(3));
+        assert_matrix_approx_eq!(&q * &qr.r, a);
+        assert_matrix_approx_eq!(
+            qr.r,
+            Matrix::from([[-14., -21., 14.], [0., -175., 70.], [0., 0., 35.]])
+        );
+        assert_eq!(qr.perm, vec![0, 1, 2]);
+        let a = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let qr = a.qr();
+        assert_eq!(qr.q().shape(), [3, 3]);
+        assert_eq!(qr.thin_q().shape(), [2, 3]);
+        assert_eq!(qr.thin_r().shape(), [2, 2]);
+        assert_matrix_approx_eq!(&qr.thin_q() * &qr.thin_r(), a);
+        assert_eq!(qr.rank(), 2);
+    }
+
+    #[test]
+    fn test_qr_pivoted() {
+        let a = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [10., 11., 12.]]);
+        let qr = a.qr_pivoted();
+        assert_eq!(qr.perm[0], 2);
+        assert_matrix_approx_eq!(&qr.q() * &qr.r, &a * &permutation(&qr.perm).transpose());
+        for k in 1..3 {
+            assert!(qr.r[(k, k)].abs() <= qr.r[(k - 1, k - 1)].abs());
+        }
+        assert_eq!(qr.rank(), 2);
+        assert_eq!(a.qr().rank(), 2);
+        assert_eq!(Matrix::<f64>::zeros(2, 3).qr_pivoted().rank(), 0);
+    }
+
+    #[test]
+    fn test_qr_complex() {
+        let c = |re, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.)],
+            [c(0., -1.), c(1., 3.)],
+            [c(2., 0.), c(0., 1.)],
+        ]);
+        let qr = a.qr();
+        let q = qr.q();
+        assert_matrix_approx_eq!(&q.transpose() * &q, Matrix::identity(3));
+        assert_matrix_approx_eq!(&q * &qr.r, a);
+        assert_eq!(qr.r[(1, 0)], Complex::from(0.));
+        let b = Vector::from([c(1., 0.), c(2., -1.), c(0., 1.)]);
+        let x = qr.solve(&b);
+        let residual = &b - &(&a * &x);
+        assert_vector_approx_eq!(
+            &a.transpose() * &residual,
+            Vector::from([c(0., 0.), c(0., 0.)])
+        );
+    }
+
+    #[test]
+    fn test_least_squares() {
+        let a = Matrix::from([[1., 0.], [1., 1.], [1., 2.], [1., 3.]]);
+        let b = Vector::from([1., 3., 4., 7.]);
+        assert_vector_approx_eq!(least_squares(&a, &b), Vector::from([0.9, 1.9]));
+        let a = Matrix::from([[2., 1.], [1., 3.]]);
+        let b = Vector::from([3., 5.]);
+        assert_vector_approx_eq!(least_squares(&a, &b), Vector::from([0.8, 1.4]));
+        let a = Matrix::from([[1., 1.], [1., 1.], [0., 0.]]);
+        let b = Vector::from([1., 3., 1.]);
+        let x = least_squares(&a, &b);
+        assert_vector_approx_eq!(&a * &x, Vector::from([2., 2., 0.]));
+        assert_eq!(x[0] * x[1], 0.);
+        let a = Matrix::from([[1., 0., 0.], [0., 0., 1.], [0., 0., 0.]]);
+        let b = Vector::from([1., 1., 0.]);
+        assert_eq!(a.qr().try_solve(&b), Err(MatrixError::Singular));
+        assert_vector_approx_eq!(least_squares(&a, &b), Vector::from([1., 0., 1.]));
+        let a = Matrix::from([[0., 1.], [0., 1.]]);
+        assert_eq!(
+            a.qr().try_solve(&Vector::from([1., 1.])),
+            Err(MatrixError::Singular)
+        );
+        assert_vector_approx_eq!(
+            least_squares(&a, &Vector::from([1., 1.])),
+            Vector::from([0., 1.])
+        );
+        assert_eq!(
+            try_least_squares(&a, &Vector::from([1., 2., 3.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+    }
+}
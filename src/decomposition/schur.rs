@@ -0,0 +1,267 @@
+use num_traits::{Float, Zero};
+
+use super::{eigen::francis, hessenberg::Hessenberg};
+use crate::{
+    error::MatrixError, ex04::Modulus, ex09::Conjugate, matrix::Matrix, scalar::ComplexField,
+    Complex,
+};
+
+/// The number of QR iterations the complex Schur decomposition allows for each eigenvalue
+/// before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// The Schur decomposition `A = Q * T * Qᴴ` of a square matrix `A`.
+#[derive(Clone, Debug)]
+pub struct Schur<K> {
+    /// The orthogonal, or unitary, factor.
+    pub q: Matrix<K>,
+    /// The Schur form: upper triangular for complex matrices, and quasi upper triangular
+    /// for real ones, with a 2x2 block on the diagonal for each complex conjugate pair of
+    /// eigenvalues.
+    pub t: Matrix<K>,
+}
+
+impl Matrix<f64> {
+    /// Computes the real Schur decomposition of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The real Schur decomposition of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if the QR iteration does not converge.
+    pub fn schur(&self) -> Schur<f64> {
+        self.try_schur().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the real Schur decomposition of a square matrix.
+    ///
+    /// The matrix is reduced to Hessenberg form, and then to quasi upper triangular form
+    /// with the Francis double-shift QR iteration.
+    ///
+    /// # Returns
+    ///
+    /// The real Schur decomposition of the matrix, a `MatrixError::NotSquare` if the
+    /// matrix is not square, or a `MatrixError::NoConvergence` if the QR iteration does
+    /// not converge.
+    pub fn try_schur(&self) -> Result<Schur<f64>, MatrixError> {
+        let Hessenberg { mut q, mut h } = self.try_hessenberg()?;
+        francis(&mut h, &mut q)?;
+        Ok(Schur { q, t: h })
+    }
+}
+
+impl Matrix<Complex<f64>> {
+    /// Computes the complex Schur decomposition of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The complex Schur decomposition of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if the QR iteration does not converge.
+    pub fn schur(&self) -> Schur<Complex<f64>> {
+        self.try_schur().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the complex Schur decomposition of a square matrix.
+    ///
+    /// The matrix is reduced to Hessenberg form, and then to upper triangular form with
+    /// the single-shift QR iteration, using Wilkinson shifts.
+    ///
+    /// # Returns
+    ///
+    /// The complex Schur decomposition of the matrix, a `MatrixError::NotSquare` if the
+    /// matrix is not square, or a `MatrixError::NoConvergence` if the QR iteration does
+    /// not converge.
+    pub fn try_schur(&self) -> Result<Schur<Complex<f64>>, MatrixError> {
+        let Hessenberg { mut q, mut h } = self.try_hessenberg()?;
+        let size = h.rows;
+        let mut norm = 0.;
+        for value in h.iter() {
+            norm += value.modulus();
+        }
+        let mut n = size.saturating_sub(1);
+        let mut iterations = 0;
+        while n > 0 {
+            let mut l = n;
+            while l > 0 {
+                let mut s = h[(l - 1, l - 1)].modulus() + h[(l, l)].modulus();
+                if s == 0. {
+                    s = norm;
+                }
+                if h[(l, l - 1)].modulus() < f64::EPSILON * s {
+                    break;
+                }
+                l -= 1;
+            }
+            if l > 0 {
+                h[(l, l - 1)] = Complex::from(0.);
+            }
+            if l == n {
+                n -= 1;
+                iterations = 0;
+                continue;
+            }
+            if iterations == MAX_ITERATIONS {
+                return Err(MatrixError::NoConvergence { iterations });
+            }
+            iterations += 1;
+            let shift = if iterations % 10 == 0 {
+                h[(n, n)] + Complex::from(h[(n, n - 1)].modulus())
+            } else {
+                wilkinson_shift(&h, n)
+            };
+            let mut x = h[(l, l)] - shift;
+            let mut y = h[(l + 1, l)];
+            for k in l..n {
+                if k > l {
+                    x = h[(k, k - 1)];
+                    y = h[(k + 1, k - 1)];
+                }
+                let Some((c, s)) = givens(x, y) else {
+                    continue;
+                };
+                for j in k.saturating_sub(1)..size {
+                    let (a, b) = (h[(k, j)], h[(k + 1, j)]);
+                    h[(k, j)] = c * a + s * b;
+                    h[(k + 1, j)] = c * b - s.conjugate() * a;
+                }
+                for i in 0..=n.min(k + 2) {
+                    let (a, b) = (h[(i, k)], h[(i, k + 1)]);
+                    h[(i, k)] = c * a + s.conjugate() * b;
+                    h[(i, k + 1)] = c * b - s * a;
+                }
+                for i in 0..size {
+                    let (a, b) = (q[(i, k)], q[(i, k + 1)]);
+                    q[(i, k)] = c * a + s.conjugate() * b;
+                    q[(i, k + 1)] = c * b - s * a;
+                }
+                if k > l {
+                    h[(k + 1, k - 1)] = Complex::from(0.);
+                }
+            }
+        }
+        for j in 0..size {
+            for i in j + 1..size {
+                h[(i, j)] = Complex::from(0.);
+            }
+        }
+        Ok(Schur { q, t: h })
+    }
+}
+
+/// Computes the eigenvalue of the trailing 2x2 block of the active part of a Hessenberg
+/// matrix that is closest to its last diagonal entry.
+///
+/// # Arguments
+///
+/// * `h` - The Hessenberg matrix.
+/// * `n` - The last row of the active part.
+///
+/// # Returns
+///
+/// The Wilkinson shift.
+fn wilkinson_shift(h: &Matrix<Complex<f64>>, n: usize) -> Complex<f64> {
+    let (a, b, c, d) = (h[(n - 1, n - 1)], h[(n - 1, n)], h[(n, n - 1)], h[(n, n)]);
+    let half = (a - d) * Complex::from(0.5);
+    let root = Complex((half * half + b * c).0.sqrt());
+    let mean = (a + d) * Complex::from(0.5);
+    let (first, second) = (mean + root, mean - root);
+    if (first - d).modulus() <= (second - d).modulus() {
+        first
+    } else {
+        second
+    }
+}
+
+/// Computes the Givens rotation that zeroes the second element of a pair.
+///
+/// # Arguments
+///
+/// * `x` - The first element.
+/// * `y` - The second element.
+///
+/// # Returns
+///
+/// The cosine `c`, which is real, and the sine `s`, which is complex for complex fields,
+/// of the unitary matrix `[[c, s], [-s̄, c]]` that maps `(x, y)` onto a multiple of
+/// `(1, 0)`, or `None` if both elements are zero.
+pub(crate) fn givens<K: ComplexField>(x: K, y: K) -> Option<(K, K)> {
+    let norm = x.modulus().hypot(y.modulus());
+    if norm.is_zero() {
+        return None;
+    }
+    let phase = if x.modulus().is_zero() {
+        K::one()
+    } else {
+        x.clone() / K::from_real(x.modulus())
+    };
+    Some((
+        K::from_real(x.modulus() / norm),
+        phase * y.conjugate() / K::from_real(norm),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+
+    #[test]
+    fn test_schur_real() {
+        let a = Matrix::from([
+            [1., 2., 0., 1.],
+            [-2., 1., 0., 0.],
+            [0., 1., 3., 1.],
+            [1., 0., 0., 2.],
+        ]);
+        let schur = a.schur();
+        let (q, t) = (&schur.q, &schur.t);
+        assert_matrix_approx_eq!(&q.transpose() * q, Matrix::identity(4));
+        assert_matrix_approx_eq!(&(q * t) * &q.transpose(), a);
+        for j in 0..4 {
+            for i in j + 2..4 {
+                assert_eq!(t[(i, j)], 0.);
+            }
+        }
+        for j in 0..2 {
+            assert!(t[(j + 1, j)] == 0. || t[(j + 2, j + 1)] == 0.);
+        }
+        let a = Matrix::from([[6., -11., 6.], [1., 0., 0.], [0., 1., 0.]]);
+        let t = a.schur().t;
+        assert_eq!((t[(1, 0)], t[(2, 1)]), (0., 0.));
+        assert_eq!(
+            Matrix::from([[1., 2.]]).try_schur().err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+    }
+
+    #[test]
+    fn test_schur_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.), c(0., -1.), c(1., 0.)],
+            [c(0., 2.), c(1., -1.), c(3., 0.), c(0., 0.)],
+            [c(1., 0.), c(0., 1.), c(2., 2.), c(-1., 1.)],
+            [c(0., 0.), c(2., 0.), c(1., 0.), c(0., -3.)],
+        ]);
+        let schur = a.schur();
+        let (q, t) = (&schur.q, &schur.t);
+        assert_matrix_approx_eq!(&q.transpose() * q, Matrix::identity(4));
+        assert_matrix_approx_eq!(&(q * t) * &q.transpose(), a);
+        for j in 0..4 {
+            for i in j + 1..4 {
+                assert_eq!(t[(i, j)], c(0., 0.));
+            }
+        }
+        let a = Matrix::from([[c(0., 0.), c(1., 0.)], [c(-1., 0.), c(0., 0.)]]);
+        let schur = a.schur();
+        let mut diagonal = [schur.t[(0, 0)].0.im, schur.t[(1, 1)].0.im];
+        diagonal.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((diagonal[0] + 1.).abs() < 1e-12 && (diagonal[1] - 1.).abs() < 1e-12);
+        assert_matrix_approx_eq!(&(&schur.q * &schur.t) * &schur.q.transpose(), a);
+    }
+}
@@ -0,0 +1,532 @@
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::{
+    error::MatrixError, ex04::Modulus, matrix::Matrix, scalar::ComplexField, vector::Vector,
+};
+
+/// The number of Jacobi sweeps after which the iteration gives up on convergence.
+const MAX_SWEEPS: usize = 64;
+
+/// The thin singular value decomposition `A = U * Σ * Vᴴ` of an `m` by `n` matrix `A`.
+#[derive(Clone, Debug)]
+pub struct Svd<K: Modulus> {
+    /// The left singular vectors, as the `min(m, n)` orthonormal columns of an `m` by
+    /// `min(m, n)` matrix.
+    pub u: Matrix<K>,
+    /// The diagonal of `Σ`, non-negative and sorted in non-increasing order.
+    pub singular_values: Vector<K::Real>,
+    /// The conjugate transpose of the right singular vectors, a `min(m, n)` by `n` matrix
+    /// with orthonormal rows.
+    pub v_h: Matrix<K>,
+}
+
+impl<K: ComplexField> Matrix<K> {
+    /// Computes the thin singular value decomposition of the matrix with the one-sided
+    /// Jacobi method.
+    ///
+    /// The columns of the matrix are rotated in pairs until they are mutually orthogonal,
+    /// which gives the singular values to high relative accuracy.
+    ///
+    /// # Returns
+    ///
+    /// The singular value decomposition of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns are not orthogonal after `MAX_SWEEPS` sweeps.
+    pub fn svd(&self) -> Svd<K> {
+        self.try_svd().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the thin singular value decomposition of the matrix with the one-sided
+    /// Jacobi method.
+    ///
+    /// # Returns
+    ///
+    /// The singular value decomposition of the matrix, or a `MatrixError::NoConvergence`
+    /// if the columns are not orthogonal after `MAX_SWEEPS` sweeps.
+    pub fn try_svd(&self) -> Result<Svd<K>, MatrixError> {
+        if self.rows < self.cols {
+            let svd = self.transpose().try_svd()?;
+            return Ok(Svd {
+                u: svd.v_h.transpose(),
+                singular_values: svd.singular_values,
+                v_h: svd.u.transpose(),
+            });
+        }
+        let (m, n) = (self.rows, self.cols);
+        let mut u = self.clone();
+        let mut v: Matrix<K> = Matrix::identity(n);
+        let epsilon = K::Real::epsilon();
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let scale = (0..m).fold(K::Real::zero(), |res, i| {
+                        nan_max(nan_max(res, u[(i, p)].modulus()), u[(i, q)].modulus())
+                    });
+                    if scale.is_zero() {
+                        continue;
+                    }
+                    let scale = K::from_real(scale);
+                    let (mut alpha, mut beta) = (K::Real::zero(), K::Real::zero());
+                    let mut gamma = K::zero();
+                    for i in 0..m {
+                        let x = u[(i, p)].clone() / scale.clone();
+                        let y = u[(i, q)].clone() / scale.clone();
+                        alpha = alpha + x.modulus().powi(2);
+                        beta = beta + y.modulus().powi(2);
+                        gamma = x.conjugate().mul_add(y, gamma);
+                    }
+                    let g = gamma.modulus();
+                    if g.is_zero() || g <= epsilon * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let phase = gamma / K::from_real(g);
+                    let zeta = (beta - alpha) / (g + g);
+                    let t = zeta.signum() / (zeta.abs() + (K::Real::one() + zeta * zeta).sqrt());
+                    let c = (K::Real::one() + t * t).sqrt().recip();
+                    rotate(&mut u, p, q, c, c * t, &phase);
+                    rotate(&mut v, p, q, c, c * t, &phase);
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+        let norms: Vec<K::Real> = (0..n).map(|j| column_norm(&u, j)).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            norms[b]
+                .partial_cmp(&norms[a])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let largest = order.first().map_or(K::Real::zero(), |&j| norms[j]);
+        let threshold = largest * epsilon * NumCast::from(m).unwrap_or(K::Real::one());
+        let mut u_cols: Vec<Vector<K>> = Vec::with_capacity(n);
+        for &j in order.iter() {
+            if norms[j] > threshold {
+                let scale = K::from_real(norms[j].recip());
+                u_cols.push((0..m).map(|i| u[(i, j)].clone() * scale.clone()).collect());
+            }
+        }
+        complete_basis(&mut u_cols, m, n);
+        Ok(Svd {
+            u: Matrix::from_fn(m, n, |i, j| u_cols[j][i].clone()),
+            singular_values: order.iter().map(|&j| norms[j]).collect(),
+            v_h: Matrix::from_fn(n, n, |i, j| v[(j, order[i])].conjugate()),
+        })
+    }
+
+    /// Computes the Moore-Penrose pseudoinverse of the matrix from its singular value
+    /// decomposition.
+    ///
+    /// The pseudoinverse exists for any shape and rank, and `pinv(tol) * b` is the
+    /// least-squares solution of `self * x = b` with the smallest norm.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - The tolerance relative to the largest singular value, at or below which
+    ///   singular values count as zero.
+    ///
+    /// # Returns
+    ///
+    /// The `n` by `m` pseudoinverse of the `m` by `n` matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the singular value decomposition does not converge.
+    pub fn pinv(&self, tol: K::Real) -> Matrix<K> {
+        self.try_pinv(tol).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the Moore-Penrose pseudoinverse of the matrix from its singular value
+    /// decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - The tolerance relative to the largest singular value, at or below which
+    ///   singular values count as zero.
+    ///
+    /// # Returns
+    ///
+    /// The `n` by `m` pseudoinverse of the `m` by `n` matrix, or a
+    /// `MatrixError::NoConvergence` if the singular value decomposition does not converge.
+    pub fn try_pinv(&self, tol: K::Real) -> Result<Matrix<K>, MatrixError> {
+        Ok(self.try_svd()?.pseudo_inverse(tol))
+    }
+}
+
+impl<K: ComplexField> Svd<K> {
+    /// Computes the numerical rank of the decomposed matrix.
+    ///
+    /// A singular value counts as zero when it is below the largest one scaled by the
+    /// machine epsilon and the largest dimension of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The number of singular values that are not negligible.
+    pub fn rank(&self) -> usize {
+        let size = self.u.shape()[1].max(self.v_h.shape()[0]);
+        let threshold = self.spectral_norm()
+            * K::Real::epsilon()
+            * NumCast::from(size).unwrap_or(K::Real::one());
+        self.singular_values
+            .iter()
+            .filter(|&&s| s > threshold)
+            .count()
+    }
+
+    /// Returns the spectral norm of the decomposed matrix.
+    ///
+    /// # Returns
+    ///
+    /// The largest singular value, or zero for an empty matrix.
+    pub fn spectral_norm(&self) -> K::Real {
+        self.singular_values
+            .iter()
+            .next()
+            .copied()
+            .unwrap_or(K::Real::zero())
+    }
+
+    /// Computes the condition number of the decomposed matrix in the spectral norm.
+    ///
+    /// # Returns
+    ///
+    /// The ratio of the largest singular value to the smallest, which is infinite if the
+    /// matrix is rank deficient, or one for an empty matrix.
+    pub fn condition_number(&self) -> K::Real {
+        match self.singular_values.iter().last() {
+            Some(&smallest) => self.spectral_norm() / smallest,
+            None => K::Real::one(),
+        }
+    }
+
+    /// Computes the best approximation of the decomposed matrix of a given rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank of the approximation, clamped to the number of singular values.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the `rank` leading terms `σᵢ * uᵢ * vᵢᴴ`, which is the closest matrix of
+    /// that rank in both the spectral and Frobenius norms.
+    pub fn low_rank_approximation(&self, rank: usize) -> Matrix<K> {
+        let [n, m] = [self.v_h.shape()[0], self.u.shape()[1]];
+        let rank = rank.min(self.singular_values.size());
+        Matrix::from_fn(m, n, |i, j| {
+            (0..rank).fold(K::zero(), |res, k| {
+                (self.u[(i, k)].clone() * K::from_real(self.singular_values[k]))
+                    .mul_add(self.v_h[(k, j)].clone(), res)
+            })
+        })
+    }
+
+    /// Computes the Moore-Penrose pseudoinverse `V * Σ⁺ * Uᴴ` of the decomposed matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - The tolerance relative to the largest singular value, at or below which
+    ///   singular values count as zero instead of being inverted.
+    ///
+    /// # Returns
+    ///
+    /// The `n` by `m` pseudoinverse of the `m` by `n` decomposed matrix.
+    pub fn pseudo_inverse(&self, tol: K::Real) -> Matrix<K> {
+        let [n, m] = [self.v_h.shape()[0], self.u.shape()[1]];
+        let threshold = tol * self.spectral_norm();
+        let inverses: Vec<K> = self
+            .singular_values
+            .iter()
+            .take_while(|&&s| s > threshold)
+            .map(|&s| K::from_real(s.recip()))
+            .collect();
+        Matrix::from_fn(n, m, |i, j| {
+            inverses
+                .iter()
+                .enumerate()
+                .fold(K::zero(), |res, (k, inverse)| {
+                    (self.v_h[(k, i)].conjugate() * inverse.clone())
+                        .mul_add(self.u[(j, k)].conjugate(), res)
+                })
+        })
+    }
+}
+
+/// Applies a complex Jacobi rotation to two columns of a matrix.
+///
+/// Column `q` is first multiplied by the conjugate of `phase`, which makes the inner
+/// product of the two columns real, and the pair is then rotated by the angle whose
+/// cosine and sine are `c` and `s`.
+///
+/// # Arguments
+///
+/// * `data` - A mutable reference to the matrix data.
+/// * `p` - The first column.
+/// * `q` - The second column.
+/// * `c` - The cosine of the rotation.
+/// * `s` - The sine of the rotation.
+/// * `phase` - The unit modulus phase of the inner product of the two columns.
+pub(super) fn rotate<K: ComplexField>(
+    data: &mut Matrix<K>,
+    p: usize,
+    q: usize,
+    c: K::Real,
+    s: K::Real,
+    phase: &K,
+) {
+    let (c, s) = (K::from_real(c), K::from_real(s));
+    for i in 0..data.rows {
+        let x = data[(i, p)].clone();
+        let y = data[(i, q)].clone() * phase.conjugate();
+        data[(i, p)] = c.clone() * x.clone() - s.clone() * y.clone();
+        data[(i, q)] = s.clone() * x + c.clone() * y;
+    }
+}
+
+/// Returns the larger of two moduli, propagating NaN unlike `Float::max`.
+///
+/// # Arguments
+///
+/// * `a` - The first modulus.
+/// * `b` - The second modulus.
+///
+/// # Returns
+///
+/// The larger modulus, or NaN if either is NaN.
+fn nan_max<R: Float>(a: R, b: R) -> R {
+    if a.is_nan() || b.is_nan() {
+        R::nan()
+    } else {
+        a.max(b)
+    }
+}
+
+/// Computes the norm of a column, scaled by its largest modulus so that the squares of
+/// tiny or huge entries neither underflow nor overflow.
+///
+/// # Arguments
+///
+/// * `data` - A reference to the matrix data.
+/// * `j` - The column.
+///
+/// # Returns
+///
+/// The norm of column `j`.
+fn column_norm<K: ComplexField>(data: &Matrix<K>, j: usize) -> K::Real {
+    let scale = (0..data.rows).fold(K::Real::zero(), |res, i| {
+        nan_max(res, data[(i, j)].modulus())
+    });
+    if scale.is_zero() {
+        return scale;
+    }
+    let sum = (0..data.rows).fold(K::Real::zero(), |res, i| {
+        (data[(i, j)].modulus() / scale).powi(2) + res
+    });
+    scale * sum.sqrt()
+}
+
+/// Extends a set of orthonormal vectors with basis vectors orthogonalized against them.
+///
+/// Each new vector comes from the standard basis vector whose part orthogonal to the
+/// current set is the longest. While fewer than `size` vectors are orthonormal, that part
+/// has a norm of at least `1 / √size`, so every step adds a vector.
+///
+/// # Arguments
+///
+/// * `cols` - A mutable reference to the orthonormal vectors.
+/// * `size` - The size of the vectors.
+/// * `count` - The number of vectors to reach, at most `size`.
+fn complete_basis<K: ComplexField>(cols: &mut Vec<Vector<K>>, size: usize, count: usize) {
+    while cols.len() < count {
+        let mut best: Option<(K::Real, Vector<K>)> = None;
+        for k in 0..size {
+            let mut w: Vector<K> = Vector::basis(k, size);
+            for _ in 0..2 {
+                for col in cols.iter() {
+                    let projection = (0..size).fold(K::zero(), |res, i| {
+                        col[i].conjugate().mul_add(w[i].clone(), res)
+                    });
+                    for i in 0..size {
+                        w[i] -= col[i].clone() * projection.clone();
+                    }
+                }
+            }
+            let norm = w.norm();
+            if best.as_ref().is_none_or(|(largest, _)| norm > *largest) {
+                best = Some((norm, w));
+            }
+        }
+        let Some((norm, w)) = best else {
+            return;
+        };
+        let scale = K::from_real(norm.recip());
+        cols.push(w.iter().map(|x| x.clone() * scale.clone()).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matrix_approx_eq, assert_vector_approx_eq, Complex};
+
+    fn reconstruct<K: ComplexField>(svd: &Svd<K>) -> Matrix<K> {
+        svd.low_rank_approximation(svd.singular_values.size())
+    }
+
+    #[test]
+    fn test_svd() {
+        let a = Matrix::from([[3f64, 2., 2.], [2., 3., -2.]]);
+        let svd = a.svd();
+        assert_vector_approx_eq!(svd.singular_values, Vector::from([5., 3.]));
+        assert_eq!(svd.u.shape(), [2, 2]);
+        assert_eq!(svd.v_h.shape(), [3, 2]);
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(2));
+        assert_matrix_approx_eq!(&svd.v_h * &svd.v_h.transpose(), Matrix::identity(2));
+        assert_matrix_approx_eq!(reconstruct(&svd), a);
+        assert_eq!(svd.rank(), 2);
+        assert!((svd.spectral_norm() - 5.).abs() < 1e-12);
+        assert!((svd.condition_number() - 5. / 3.).abs() < 1e-12);
+        let a = Matrix::from([[1., 0.], [0., -4.], [0., 0.]]);
+        let svd = a.svd();
+        assert_vector_approx_eq!(svd.singular_values, Vector::from([4., 1.]));
+        assert_matrix_approx_eq!(reconstruct(&svd), a);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let a = Matrix::from([[1f64, 2.], [2., 4.], [3., 6.]]);
+        let svd = a.svd();
+        assert!((svd.singular_values[0] - 70f64.sqrt()).abs() < 1e-12);
+        assert!(svd.singular_values[1].abs() < 1e-12);
+        assert_eq!(svd.rank(), 1);
+        assert!(svd.condition_number() > 1e12);
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(2));
+        assert_matrix_approx_eq!(svd.low_rank_approximation(1), a);
+        let svd = Matrix::<f64>::zeros(2, 2).svd();
+        assert_eq!(svd.singular_values, Vector::from([0., 0.]));
+        assert_eq!(svd.rank(), 0);
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(2));
+        let a = Matrix::from([[1., f64::NAN], [0., 1.]]);
+        assert_eq!(
+            a.try_svd().err(),
+            Some(MatrixError::NoConvergence {
+                iterations: MAX_SWEEPS
+            })
+        );
+    }
+
+    #[test]
+    fn test_svd_scaling() {
+        let svd = Matrix::from([[1e-300, 0.], [0., 1.]]).svd();
+        assert_vector_approx_eq!(svd.singular_values, Vector::from([1., 1e-300]), rel = 1e-12);
+        let a = Matrix::from([[3e-300, 2e-300, 2e-300], [2e-300, 3e-300, -2e-300]]);
+        let svd = a.svd();
+        assert_vector_approx_eq!(
+            svd.singular_values,
+            Vector::from([5e-300, 3e-300]),
+            rel = 1e-12
+        );
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(2));
+        assert_matrix_approx_eq!(&svd.v_h * &svd.v_h.transpose(), Matrix::identity(2));
+    }
+
+    #[test]
+    fn test_svd_complete_basis() {
+        let a = Matrix::from_fn(5, 5, |i, j| if i == j { 0.8 } else { -0.2 });
+        let svd = a.svd();
+        assert_eq!(svd.rank(), 4);
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(5));
+        assert_matrix_approx_eq!(&svd.v_h * &svd.v_h.transpose(), Matrix::identity(5));
+        assert_matrix_approx_eq!(reconstruct(&svd), a);
+    }
+
+    #[test]
+    fn test_svd_low_rank_approximation() {
+        let a = Matrix::from([[4., 0., 0.], [0., 3., 0.], [0., 0., 1.]]);
+        let svd = a.svd();
+        assert_matrix_approx_eq!(
+            svd.low_rank_approximation(2),
+            Matrix::from([[4., 0., 0.], [0., 3., 0.], [0., 0., 0.]])
+        );
+        assert_matrix_approx_eq!(svd.low_rank_approximation(0), Matrix::zeros(3, 3));
+    }
+
+    #[test]
+    fn test_svd_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.), c(0., -1.)],
+            [c(0., 2.), c(1., -1.), c(3., 0.)],
+        ]);
+        let svd = a.svd();
+        assert_matrix_approx_eq!(&svd.u.transpose() * &svd.u, Matrix::identity(2));
+        assert_matrix_approx_eq!(&svd.v_h * &svd.v_h.transpose(), Matrix::identity(2));
+        assert_matrix_approx_eq!(reconstruct(&svd), a);
+        let frobenius = a.iter().map(|x| x.modulus().powi(2)).sum::<f64>();
+        let squares = svd.singular_values.iter().map(|s| s * s).sum::<f64>();
+        assert!((frobenius - squares).abs() < 1e-12);
+        assert!(svd.singular_values[0] >= svd.singular_values[1]);
+    }
+
+    #[test]
+    fn test_pinv() {
+        let a = Matrix::from([[2., 1.], [1., 3.]]);
+        assert_matrix_approx_eq!(a.pinv(1e-12), a.inverse());
+        let a = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let pinv = a.pinv(1e-12);
+        assert_eq!(pinv.shape(), [3, 2]);
+        assert_matrix_approx_eq!(&pinv * &a, Matrix::identity(2));
+        assert_matrix_approx_eq!(&(&a * &pinv) * &a, a);
+        assert_matrix_approx_eq!(
+            Matrix::from([[1., 1.]]).pinv(1e-12),
+            Matrix::from([[0.5], [0.5]])
+        );
+        let a = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_matrix_approx_eq!(a.pinv(1e-12), Matrix::from([[0.04, 0.08], [0.08, 0.16]]));
+        let a = Matrix::from([[1., 0.], [0., 1e-14]]);
+        assert_matrix_approx_eq!(a.pinv(1e-12), Matrix::from([[1., 0.], [0., 0.]]));
+        assert_eq!(Matrix::<f64>::zeros(2, 3).pinv(0.), Matrix::zeros(3, 2));
+        assert_eq!(
+            Matrix::from([[1., f64::NAN], [0., 1.]]).try_pinv(1e-12),
+            Err(MatrixError::NoConvergence {
+                iterations: MAX_SWEEPS
+            })
+        );
+    }
+
+    #[test]
+    fn test_pinv_rank_deficient() {
+        let a = Matrix::from_fn(5, 5, |i, j| if i == j { 0.8 } else { -0.2 });
+        let pinv = a.try_pinv(1e-12).unwrap();
+        assert_matrix_approx_eq!(pinv, a);
+        assert_matrix_approx_eq!(&(&a * &pinv) * &a, a);
+        assert_matrix_approx_eq!(&(&pinv * &a) * &pinv, pinv);
+    }
+
+    #[test]
+    fn test_pinv_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.), c(0., -1.)],
+            [c(0., 2.), c(1., -1.), c(3., 0.)],
+        ]);
+        let pinv = a.pinv(1e-12);
+        assert_matrix_approx_eq!(&a * &pinv, Matrix::identity(2));
+        assert_matrix_approx_eq!(&(&pinv * &a) * &pinv, pinv);
+        assert_matrix_approx_eq!(
+            Matrix::from([[c(0., 2.)]]).pinv(0.),
+            Matrix::from([[c(0., -0.5)]])
+        );
+    }
+}
@@ -0,0 +1,79 @@
+/// An error type for the fallible operations on vectors and matrices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The operands do not have compatible shapes.
+    ///
+    /// Shapes use the same `[columns, rows]` layout as `Matrix::shape`, vectors being
+    /// reported as single column matrices.
+    ShapeMismatch {
+        /// The shape the operation required.
+        expected: [usize; 2],
+        /// The shape that was provided.
+        found: [usize; 2],
+    },
+    /// The operation is only defined for square matrices.
+    NotSquare {
+        /// The shape of the offending matrix.
+        shape: [usize; 2],
+    },
+    /// The matrix is singular and cannot be inverted.
+    Singular,
+    /// The matrix is not Hermitian positive definite.
+    NotPositiveDefinite,
+    /// The matrix is not symmetric, or not Hermitian for complex scalars.
+    NotSymmetric,
+    /// An iterative method did not reach its tolerance.
+    NoConvergence {
+        /// The number of iterations performed.
+        iterations: usize,
+    },
+    /// The operation needs at least one element to work on.
+    Empty,
+    /// An intermediate result does not fit in the scalar type.
+    Overflow,
+}
+
+impl std::fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MatrixError::ShapeMismatch { expected, found } => write!(
+                f,
+                "Shape mismatch: expected {}x{}, found {}x{}",
+                expected[1], expected[0], found[1], found[0]
+            ),
+            MatrixError::NotSquare { shape } => {
+                write!(f, "Matrix is not square: found {}x{}", shape[1], shape[0])
+            }
+            MatrixError::Singular => write!(f, "Matrix is singular"),
+            MatrixError::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+            MatrixError::NotSymmetric => write!(f, "Matrix is not symmetric"),
+            MatrixError::NoConvergence { iterations } => {
+                write!(f, "No convergence after {} iterations", iterations)
+            }
+            MatrixError::Empty => write!(f, "Operation requires a non-empty input"),
+            MatrixError::Overflow => write!(f, "Arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = MatrixError::ShapeMismatch {
+            expected: [3, 2],
+            found: [2, 2],
+        };
+        assert_eq!(e.to_string(), "Shape mismatch: expected 2x3, found 2x2");
+        let e = MatrixError::NotSquare { shape: [3, 2] };
+        assert_eq!(e.to_string(), "Matrix is not square: found 2x3");
+        let e = MatrixError::NotPositiveDefinite;
+        assert_eq!(e.to_string(), "Matrix is not positive definite");
+        let e = MatrixError::NoConvergence { iterations: 30 };
+        assert_eq!(e.to_string(), "No convergence after 30 iterations");
+    }
+}
@@ -1,6 +1,6 @@
-use crate::{matrix::Matrix, vector::Vector};
+use crate::{error::MatrixError, matrix::Matrix, scalar::Scalar, vector::Vector};
 
-impl<K: std::ops::Add<Output = K> + Copy + Default> Vector<K> {
+impl<K: Scalar> Vector<K> {
     /// Adds two vectors element-wise.
     ///
     /// # Arguments
@@ -15,17 +15,33 @@ impl<K: std::ops::Add<Output = K> + Copy + Default> Vector<K> {
     ///
     /// Panics if the vectors are not the same size.
     pub fn _add(&self, v: &Vector<K>) -> Self {
+        self.try_add(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Adds two vectors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector to add.
+    ///
+    /// # Returns
+    ///
+    /// A new vector that is the element-wise sum of the two vectors, or a
+    /// `MatrixError::ShapeMismatch` if the vectors are not the same size.
+    pub fn try_add(&self, v: &Vector<K>) -> Result<Self, MatrixError> {
         if !self.is_same_size(v) {
-            panic!()
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: v.shape(),
+            });
         }
-        let mut res = Vector::from(vec![K::default(); self.size()]);
+        let mut res = Vector::zeros(self.size());
         for i in 0..self.size() {
-            res.data[i] = self.data[i] + v.data[i];
+            res.data[i] = self.data[i].clone() + v.data[i].clone();
         }
-        res
+        Ok(res)
     }
-}
-impl<K: std::ops::Sub<Output = K> + Copy + Default> Vector<K> {
+
     /// Subtracts one vector from another element-wise.
     ///
     /// # Arguments
@@ -40,18 +56,33 @@ impl<K: std::ops::Sub<Output = K> + Copy + Default> Vector<K> {
     ///
     /// Panics if the vectors are not the same size.
     pub fn _sub(&self, v: &Vector<K>) -> Self {
+        self.try_sub(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Subtracts one vector from another element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new vector that is the element-wise difference of the two vectors, or a
+    /// `MatrixError::ShapeMismatch` if the vectors are not the same size.
+    pub fn try_sub(&self, v: &Vector<K>) -> Result<Self, MatrixError> {
         if !self.is_same_size(v) {
-            panic!()
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: v.shape(),
+            });
         }
-        let mut res = Vector::from(vec![K::default(); self.size()]);
+        let mut res = Vector::zeros(self.size());
         for i in 0..self.size() {
-            res.data[i] = self.data[i] - v.data[i];
+            res.data[i] = self.data[i].clone() - v.data[i].clone();
         }
-        res
+        Ok(res)
     }
-}
 
-impl<K: Copy + Default> Vector<K> {
     /// Multiplies a vector by a scalar.
     ///
     /// # Arguments
@@ -64,16 +95,17 @@ impl<K: Copy + Default> Vector<K> {
     pub fn _scl<F>(&self, a: F) -> Self
     where
         K: std::ops::Mul<F, Output = K>,
-        F: Copy,
+        F: Clone,
     {
-        let mut res = Vector::from(vec![K::default(); self.size()]);
+        let mut res = Vector::zeros(self.size());
         for i in 0..self.size() {
-            res.data[i] = self.data[i] * a;
+            res.data[i] = self.data[i].clone() * a.clone();
         }
         res
     }
 }
-impl<K: std::ops::Add<Output = K> + Copy + Default> Matrix<K> {
+
+impl<K: Scalar> Matrix<K> {
     /// Adds two matrices element-wise.
     ///
     /// # Arguments
@@ -88,19 +120,33 @@ impl<K: std::ops::Add<Output = K> + Copy + Default> Matrix<K> {
     ///
     /// Panics if the matrices do not have the same shape.
     pub fn _add(&self, m: &Matrix<K>) -> Self {
+        self.try_add(m).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Adds two matrices element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - Another matrix to add.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the element-wise sum of the two matrices, or a
+    /// `MatrixError::ShapeMismatch` if the matrices do not have the same shape.
+    pub fn try_add(&self, m: &Matrix<K>) -> Result<Self, MatrixError> {
         if !self.is_same_shape(m) {
-            panic!()
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: m.shape(),
+            });
         }
-        let mut res = Matrix::from(vec![vec![K::default(); self.shape()[0]]; self.shape()[1]]);
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                res.data[i][j] = self.data[i][j] + m.data[i][j];
-            }
+        let mut res = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.data.len() {
+            res.data[i] = self.data[i].clone() + m.data[i].clone();
         }
-        res
+        Ok(res)
     }
-}
-impl<K: std::ops::Sub<Output = K> + Copy + Default> Matrix<K> {
+
     /// Subtracts one matrix from another element-wise.
     ///
     /// # Arguments
@@ -115,19 +161,33 @@ impl<K: std::ops::Sub<Output = K> + Copy + Default> Matrix<K> {
     ///
     /// Panics if the matrices do not have the same shape.
     pub fn _sub(&self, m: &Matrix<K>) -> Self {
+        self.try_sub(m).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Subtracts one matrix from another element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - Another matrix to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the element-wise difference of the two matrices, or a
+    /// `MatrixError::ShapeMismatch` if the matrices do not have the same shape.
+    pub fn try_sub(&self, m: &Matrix<K>) -> Result<Self, MatrixError> {
         if !self.is_same_shape(m) {
-            panic!()
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: m.shape(),
+            });
         }
-        let mut res = Matrix::from(vec![vec![K::default(); self.shape()[0]]; self.shape()[1]]);
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                res.data[i][j] = self.data[i][j] - m.data[i][j];
-            }
+        let mut res = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.data.len() {
+            res.data[i] = self.data[i].clone() - m.data[i].clone();
         }
-        res
+        Ok(res)
     }
-}
-impl<K: Copy + Default> Matrix<K> {
+
     /// Multiplies a matrix by a scalar.
     ///
     /// # Arguments
@@ -140,13 +200,11 @@ impl<K: Copy + Default> Matrix<K> {
     pub fn _scl<F>(&self, a: F) -> Self
     where
         K: std::ops::Mul<F, Output = K>,
-        F: Copy,
+        F: Clone,
     {
-        let mut res = Matrix::from(vec![vec![K::default(); self.shape()[0]]; self.shape()[1]]);
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                res.data[i][j] = self.data[i][j] * a;
-            }
+        let mut res = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.data.len() {
+            res.data[i] = self.data[i].clone() * a.clone();
         }
         res
     }
@@ -204,4 +262,46 @@ mod tests {
         let w = u._scl(2.);
         assert_eq!(w, Matrix::from([[2.0, 4.0], [6.0, 8.0]]));
     }
+
+    #[test]
+    fn test_vector_try_add_sub() {
+        let u = Vector::from([2., 3.]);
+        let v = Vector::from([5., 7., 1.]);
+        assert_eq!(
+            u.try_add(&v),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+        assert!(u.try_sub(&v).is_err());
+        assert_eq!(
+            u.try_add(&Vector::from([1., 1.])),
+            Ok(Vector::from([3., 4.]))
+        );
+    }
+
+    #[test]
+    fn test_matrix_try_add_sub() {
+        let u = Matrix::from([[1., 2., 3.], [3., 4., 5.]]);
+        let v = Matrix::from([[7., 4.], [-2., 2.]]);
+        assert_eq!(
+            u.try_add(&v),
+            Err(MatrixError::ShapeMismatch {
+                expected: [3, 2],
+                found: [2, 2]
+            })
+        );
+        assert!(u.try_sub(&v).is_err());
+        assert_eq!(
+            u.try_sub(&Matrix::from([[1., 1., 1.], [1., 1., 1.]])),
+            Ok(Matrix::from([[0., 1., 2.], [2., 3., 4.]]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Shape mismatch")]
+    fn test_vector_add_panics() {
+        Vector::from([2., 3.])._add(&Vector::from([1.]));
+    }
 }
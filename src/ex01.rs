@@ -1,5 +1,4 @@
-use crate::vector::Vector;
-use num_traits::MulAdd;
+use crate::{error::MatrixError, scalar::Scalar, vector::Vector};
 
 /// Compute the linear combination of vectors.
 ///
@@ -11,17 +10,54 @@ use num_traits::MulAdd;
 /// # Returns
 ///
 /// A new vector that is the linear combination of the input vectors.
-pub fn linear_combination<K: std::marker::Copy + Default + MulAdd<Output = K>>(
+///
+/// # Panics
+///
+/// Panics if `u` is empty, if there are not as many coefficients as vectors or if the
+/// vectors are not the same size.
+pub fn linear_combination<K: Scalar>(u: &[&Vector<K>], coefs: &[K]) -> Vector<K> {
+    try_linear_combination(u, coefs).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Compute the linear combination of vectors.
+///
+/// # Arguments
+///
+/// * `u` - A slice of vectors.
+/// * `coefs` - A slice of coefficients.
+///
+/// # Returns
+///
+/// A new vector that is the linear combination of the input vectors, a
+/// `MatrixError::Empty` if `u` is empty, or a `MatrixError::ShapeMismatch` if there are
+/// not as many coefficients as vectors or if the vectors are not the same size.
+pub fn try_linear_combination<K: Scalar>(
     u: &[&Vector<K>],
     coefs: &[K],
-) -> Vector<K> {
-    let mut res = vec![K::default(); u[0].size()];
-    for i in 0..u[0].size() {
-        for j in 0..u.len() {
-            res[i] = u[j].data[i].mul_add(coefs[j], res[i]);
-        }
+) -> Result<Vector<K>, MatrixError> {
+    if u.is_empty() {
+        return Err(MatrixError::Empty);
     }
-    Vector::from(res)
+    if u.len() != coefs.len() {
+        return Err(MatrixError::ShapeMismatch {
+            expected: [1, u.len()],
+            found: [1, coefs.len()],
+        });
+    }
+    if let Some(v) = u.iter().find(|v| !v.is_same_size(u[0])) {
+        return Err(MatrixError::ShapeMismatch {
+            expected: u[0].shape(),
+            found: v.shape(),
+        });
+    }
+    let res = (0..u[0].size())
+        .map(|i| {
+            u.iter().zip(coefs).fold(K::zero(), |res, (v, coef)| {
+                v.data[i].clone().mul_add(coef.clone(), res)
+            })
+        })
+        .collect();
+    Ok(res)
 }
 
 #[cfg(test)]
@@ -45,4 +81,28 @@ mod tests {
             linear_combination(&[&v1, &v2], &[10., -2.])
         );
     }
+
+    #[test]
+    fn test_try_linear_combination() {
+        let v1 = Vector::from([1., 2., 3.]);
+        let v2 = Vector::from([0., 10.]);
+        assert_eq!(
+            try_linear_combination::<f64>(&[], &[]),
+            Err(MatrixError::Empty)
+        );
+        assert_eq!(
+            try_linear_combination(&[&v1, &v2], &[10.]),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 1]
+            })
+        );
+        assert_eq!(
+            try_linear_combination(&[&v1, &v2], &[10., -2.]),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+    }
 }
@@ -1,5 +1,75 @@
 use num_traits::MulAdd;
 
+use crate::{error::MatrixError, matrix::Matrix, vector::Vector, Complex};
+
+/// A fused multiply-add `self * a + b` that fails on operands of different shapes.
+pub trait TryMulAdd: Sized {
+    /// Multiplies a value by a scalar and adds another value of the same shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The scalar to multiply by.
+    /// * `b` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// The result of the operation, or a `MatrixError::ShapeMismatch` if `b` does not
+    /// have the shape of `self`.
+    fn try_mul_add(self, a: f32, b: Self) -> Result<Self, MatrixError>;
+}
+
+/// Implements `TryMulAdd` for each of the given scalar types, which always match.
+macro_rules! impl_scalar_try_mul_add {
+    ($($scalar:ty),*) => {$(
+        impl TryMulAdd for $scalar {
+            fn try_mul_add(self, a: f32, b: Self) -> Result<Self, MatrixError> {
+                Ok(self.mul_add(a, b))
+            }
+        }
+    )*};
+}
+
+impl_scalar_try_mul_add!(f32, Complex<f32>);
+
+impl<K: Copy + Default + MulAdd<f32, K, Output = K>> TryMulAdd for Vector<K> {
+    fn try_mul_add(self, a: f32, b: Self) -> Result<Self, MatrixError> {
+        if !self.is_same_size(&b) {
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: b.shape(),
+            });
+        }
+        Ok(self
+            .data
+            .iter()
+            .zip(b.data.iter())
+            .map(|(&x, &y)| x.mul_add(a, y))
+            .collect())
+    }
+}
+
+impl<K: Copy + Default + MulAdd<f32, K, Output = K>> TryMulAdd for Matrix<K> {
+    fn try_mul_add(self, a: f32, b: Self) -> Result<Self, MatrixError> {
+        if !self.is_same_shape(&b) {
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: b.shape(),
+            });
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(b.data.iter())
+            .map(|(&x, &y)| x.mul_add(a, y))
+            .collect();
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+}
+
 /// Linearly interpolate between two values.
 ///
 /// # Arguments
@@ -11,6 +81,10 @@ use num_traits::MulAdd;
 /// # Returns
 ///
 /// The linear interpolation between `u` and `v` at parameter `t`.
+///
+/// # Panics
+///
+/// Panics if `u` and `v` are vectors or matrices of different shapes.
 pub fn lerp<
     V: MulAdd<f32, V, Output = V> + std::ops::Sub<Output = V> + Clone + std::ops::Mul<f32, Output = V>,
 >(
@@ -21,6 +95,26 @@ pub fn lerp<
     (u).mul_add(1. - t, v * t)
 }
 
+/// Linearly interpolate between two values.
+///
+/// # Arguments
+///
+/// * `u` - The first value.
+/// * `v` - The second value.
+/// * `t` - The interpolation parameter.
+///
+/// # Returns
+///
+/// The linear interpolation between `u` and `v` at parameter `t`, or a
+/// `MatrixError::ShapeMismatch` if `u` and `v` do not have the same shape.
+pub fn try_lerp<V: TryMulAdd + std::ops::Mul<f32, Output = V>>(
+    u: V,
+    v: V,
+    t: f32,
+) -> Result<V, MatrixError> {
+    u.try_mul_add(1. - t, v * t)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{matrix::Matrix, vector::Vector};
@@ -46,4 +140,27 @@ mod tests {
             Matrix::from([[11., 5.5], [16.5, 22.]])
         );
     }
+
+    #[test]
+    fn test_try_lerp() {
+        assert_eq!(try_lerp(21f32, 42., 0.3), Ok(27.3));
+        assert_eq!(
+            try_lerp(Vector::from([2., 1.]), Vector::from([4., 2.]), 0.3),
+            Ok(Vector::from([2.6, 1.3]))
+        );
+        assert_eq!(
+            try_lerp(Vector::from([2., 1.]), Vector::from([4.]), 0.3),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 1]
+            })
+        );
+        assert_eq!(
+            try_lerp(Matrix::from([[2., 1.]]), Matrix::from([[2.], [1.]]), 0.5),
+            Err(MatrixError::ShapeMismatch {
+                expected: [2, 1],
+                found: [1, 2]
+            })
+        );
+    }
 }
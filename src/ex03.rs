@@ -1,17 +1,6 @@
-use num_traits::MulAdd;
+use crate::{error::MatrixError, scalar::Scalar, vector::Vector};
 
-use crate::vector::Vector;
-
-impl<
-        K: Copy
-            + Default
-            + From<f32>
-            + PartialEq
-            + std::ops::Mul<Output = K>
-            + std::ops::Add<Output = K>
-            + MulAdd<Output = K>,
-    > Vector<K>
-{
+impl<K: Scalar> Vector<K> {
     /// Computes the dot product of two vectors.
     ///
     /// # Arguments
@@ -21,12 +10,36 @@ impl<
     /// # Returns
     ///
     /// The dot product of the two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same size.
     pub fn dot(&self, v: &Vector<K>) -> K {
-        let mut res = K::default();
+        self.try_dot(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the dot product of two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector to compute the dot product with.
+    ///
+    /// # Returns
+    ///
+    /// The dot product of the two vectors, or a `MatrixError::ShapeMismatch` if the
+    /// vectors are not the same size.
+    pub fn try_dot(&self, v: &Vector<K>) -> Result<K, MatrixError> {
+        if !self.is_same_size(v) {
+            return Err(MatrixError::ShapeMismatch {
+                expected: self.shape(),
+                found: v.shape(),
+            });
+        }
+        let mut res = K::zero();
         for i in 0..self.size() {
-            res = v.data[i].mul_add(self.data[i], res);
+            res = v.data[i].clone().mul_add(self.data[i].clone(), res);
         }
-        res
+        Ok(res)
     }
 }
 
@@ -48,4 +61,17 @@ mod tests {
         let v = Vector::from([3., 2.]);
         assert_eq!(9., u.dot(&v));
     }
+
+    #[test]
+    fn test_try_dot() {
+        let u = Vector::from([-1., 6.]);
+        assert_eq!(u.try_dot(&Vector::from([3., 2.])), Ok(9.));
+        assert_eq!(
+            u.try_dot(&Vector::from([3., 2., 1.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+    }
 }
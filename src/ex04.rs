@@ -1,25 +1,18 @@
-use num::complex::ComplexFloat;
-use num_traits::Pow;
-
-use crate::{vector::Vector, Complex};
-
-impl<
-        K: Copy
-            + Default
-            + From<f32>
-            + PartialEq
-            + std::ops::Mul<Output = K>
-            + std::ops::Add<Output = K>
-            + Modulus,
-    > Vector<K>
-{
+use num::{bigint::BigInt, rational::Ratio};
+use num_traits::{Float, ToPrimitive, Zero};
+
+use crate::{scalar::Scalar, vector::Vector, Complex};
+
+impl<K: Scalar + Modulus> Vector<K> {
     /// Computes the 1-norm of a vector.
     ///
     /// # Returns
     ///
     /// The 1-norm of the vector.
-    pub fn norm_1(&self) -> f32 {
-        self.data.iter().map(|x| x.modulus()).sum()
+    pub fn norm_1(&self) -> K::Real {
+        self.data
+            .iter()
+            .fold(K::Real::zero(), |res, x| res + x.modulus())
     }
 
     /// Computes the Euclidean norm of a vector.
@@ -27,13 +20,13 @@ impl<
     /// # Returns
     ///
     /// The Euclidean norm of the vector.
-    pub fn norm(&self) -> f32 {
-        let mut res = f32::default();
+    pub fn norm(&self) -> K::Real {
+        let mut res = K::Real::zero();
         for i in 0..self.size() {
             let modulus = self.data[i].modulus();
             res = modulus.mul_add(modulus, res);
         }
-        res.pow(0.5)
+        res.sqrt()
     }
 
     /// Computes the infinity norm of a vector.
@@ -41,53 +34,74 @@ impl<
     /// # Returns
     ///
     /// The infinity norm of the vector.
-    pub fn norm_inf(&self) -> f32 {
-        self.data.iter().map(|x| x.modulus()).fold(0., f32::max)
+    pub fn norm_inf(&self) -> K::Real {
+        self.data
+            .iter()
+            .fold(K::Real::zero(), |res, x| res.max(x.modulus()))
     }
 }
 
 pub trait Modulus {
+    /// The real type in which the modulus is expressed.
+    type Real: Float + Default + std::fmt::Debug;
+
     /// Computes the modulus of a number.
     ///
     /// # Returns
     ///
     /// The modulus of the number.
-    fn modulus(&self) -> f32;
+    fn modulus(&self) -> Self::Real;
 }
 
 impl Modulus for f32 {
+    type Real = f32;
+
     fn modulus(&self) -> f32 {
-        let res = *self;
-        if res < 0. {
-            return -res;
-        }
-        res
+        self.abs()
     }
 }
 
 impl Modulus for f64 {
-    fn modulus(&self) -> f32 {
-        let res = *self as f32;
-        if res < 0. {
-            return -res;
-        }
-        res
+    type Real = f64;
+
+    fn modulus(&self) -> f64 {
+        self.abs()
     }
 }
 
 impl Modulus for Complex<f32> {
+    type Real = f32;
+
     fn modulus(&self) -> f32 {
-        (self.0.re().pow(2) as f32 + self.0.im().pow(2) as f32).pow(0.5)
+        self.0.norm()
     }
 }
 
 impl Modulus for Complex<f64> {
-    fn modulus(&self) -> f32 {
-        (self.0.re().pow(2) as f32 + self.0.im().pow(2) as f32).pow(0.5)
+    type Real = f64;
+
+    fn modulus(&self) -> f64 {
+        self.0.norm()
     }
 }
 
+macro_rules! impl_ratio_modulus {
+    ($($t:ty),*) => {$(
+        impl Modulus for Ratio<$t> {
+            type Real = f64;
+
+            /// Computes the modulus of a rational, rounded to the nearest `f64`.
+            fn modulus(&self) -> f64 {
+                self.to_f64().map_or(f64::INFINITY, f64::abs)
+            }
+        }
+    )*};
+}
+
+impl_ratio_modulus!(i64, BigInt);
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use super::*;
 
@@ -106,9 +120,9 @@ mod tests {
         let u = Vector::from([0., 0., 0.]);
         assert_eq!(u.norm(), 0.);
         let u = Vector::from([1., 2., 3.]);
-        assert_eq!(u.norm(), 3.74165738);
+        assert_eq!(u.norm(), 3.7416573867739413);
         let u = Vector::from([-1., -2.]);
-        assert_eq!(u.norm(), 2.236067977);
+        assert_eq!(u.norm(), 2.23606797749979);
     }
 
     #[test]
@@ -120,4 +134,14 @@ mod tests {
         let u = Vector::from([-1., -2.]);
         assert_eq!(u.norm_inf(), 2.);
     }
+
+    #[test]
+    fn test_norm_precision() {
+        let u = Vector::from([1e-3f64, 1e-12]);
+        assert_eq!(u.norm_1(), 1.000000001e-3);
+        let u = Vector::from([Complex(num::Complex::new(1. + 1e-9, 0.))]);
+        assert_eq!(u.norm(), 1.000000001);
+        let u = Vector::from([Complex(num::Complex::new(3f64, 4.))]);
+        assert_eq!(u.norm(), 5.);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{ex04::Modulus, vector::Vector};
+use crate::{error::MatrixError, scalar::Field, vector::Vector};
 
 /// Computes the cosine of the angle between two vectors.
 ///
@@ -10,22 +10,34 @@ use crate::{ex04::Modulus, vector::Vector};
 /// # Returns
 ///
 /// The cosine of the angle between the two vectors.
-pub fn angle_cos<
-    K: Copy
-        + Default
-        + Modulus
-        + PartialEq
-        + From<f32>
-        + std::ops::Add<Output = K>
-        + std::ops::Mul<Output = K>
-        + std::ops::Div<Output = K>
-        + num_traits::Pow<f32, Output = K>
-        + num_traits::ops::mul_add::MulAdd<Output = K>,
->(
+///
+/// # Panics
+///
+/// Panics if the vectors are empty or not the same size.
+pub fn angle_cos<K: Field + num_traits::Pow<f32, Output = K>>(u: &Vector<K>, v: &Vector<K>) -> K {
+    try_angle_cos(u, v).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Computes the cosine of the angle between two vectors.
+///
+/// # Arguments
+///
+/// * `u` - The first vector.
+/// * `v` - The second vector.
+///
+/// # Returns
+///
+/// The cosine of the angle between the two vectors, a `MatrixError::Empty` if the
+/// vectors have no elements, or a `MatrixError::ShapeMismatch` if they are not the same
+/// size.
+pub fn try_angle_cos<K: Field + num_traits::Pow<f32, Output = K>>(
     u: &Vector<K>,
     v: &Vector<K>,
-) -> K {
-    u.dot(v) / (u.dot(u) * v.dot(v)).pow(0.5)
+) -> Result<K, MatrixError> {
+    if u.size() == 0 {
+        return Err(MatrixError::Empty);
+    }
+    Ok(u.try_dot(v)? / (u.dot(u) * v.dot(v)).pow(0.5))
 }
 
 #[cfg(test)]
@@ -50,4 +62,19 @@ mod tests {
         let v = Vector::from([4., 5., 6.]);
         assert_eq!(angle_cos(&u, &v), 0.9746318461970762);
     }
+
+    #[test]
+    fn test_try_angle_cos() {
+        let u = Vector::from([1., 0.]);
+        assert_eq!(try_angle_cos(&u, &Vector::from([1., 0.])), Ok(1.));
+        assert_eq!(
+            try_angle_cos(&u, &Vector::from([1., 0., 0.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+        let empty: Vector<f64> = Vector::from(vec![]);
+        assert_eq!(try_angle_cos(&empty, &empty), Err(MatrixError::Empty));
+    }
 }
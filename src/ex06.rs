@@ -1,4 +1,4 @@
-use crate::vector::Vector;
+use crate::{error::MatrixError, scalar::Scalar, vector::Vector};
 
 /// Computes the cross product of two vectors.
 ///
@@ -10,15 +10,39 @@ use crate::vector::Vector;
 /// # Returns
 ///
 /// The cross product of the two vectors.
-pub fn cross_product<K: Copy + Default + std::ops::Mul<Output = K> + std::ops::Sub<Output = K>>(
+///
+/// # Panics
+///
+/// Panics if either vector does not have exactly three elements.
+pub fn cross_product<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
+    try_cross_product(u, v).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Computes the cross product of two vectors.
+///
+/// # Arguments
+///
+/// * `u` - The first vector.
+/// * `v` - The second vector.
+///
+/// # Returns
+///
+/// The cross product of the two vectors, or a `MatrixError::ShapeMismatch` if either
+/// vector does not have exactly three elements.
+pub fn try_cross_product<K: Scalar>(
     u: &Vector<K>,
     v: &Vector<K>,
-) -> Vector<K> {
-    let mut data = [K::default(); 3];
-    data[0] = u.data[1] * v.data[2] - u.data[2] * v.data[1];
-    data[1] = u.data[2] * v.data[0] - u.data[0] * v.data[2];
-    data[2] = u.data[0] * v.data[1] - u.data[1] * v.data[0];
-    Vector::from(data)
+) -> Result<Vector<K>, MatrixError> {
+    if let Some(w) = [u, v].into_iter().find(|w| w.size() != 3) {
+        return Err(MatrixError::ShapeMismatch {
+            expected: [1, 3],
+            found: w.shape(),
+        });
+    }
+    let minor = |i: usize, j: usize| {
+        u.data[i].clone() * v.data[j].clone() - u.data[j].clone() * v.data[i].clone()
+    };
+    Ok(Vector::from([minor(1, 2), minor(2, 0), minor(0, 1)]))
 }
 
 #[cfg(test)]
@@ -39,4 +63,18 @@ mod tests {
         let v = Vector::from([-2., -5., 16.]);
         assert_eq!(cross_product(&u, &v), Vector::from([17., -58., -16.]));
     }
+
+    #[test]
+    fn test_try_cross_product() {
+        let u = Vector::from([1., 2., 3.]);
+        let v = Vector::from([4., 5.]);
+        assert_eq!(
+            try_cross_product(&u, &v),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+        assert!(try_cross_product(&v, &u).is_err());
+    }
 }
@@ -1,13 +1,6 @@
-use crate::{matrix::Matrix, vector::Vector};
+use crate::{error::MatrixError, matrix::Matrix, scalar::Scalar, vector::Vector};
 
-impl<
-        K: Copy
-            + Default
-            + std::ops::Mul<Output = K>
-            + std::ops::AddAssign
-            + num_traits::MulAdd<Output = K>,
-    > Matrix<K>
-{
+impl<K: Scalar> Matrix<K> {
     /// Multiplies a matrix by a vector.
     ///
     /// # Arguments
@@ -17,14 +10,38 @@ impl<
     /// # Returns
     ///
     /// A new vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the vector differs from the number of columns of the matrix.
     pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
-        let mut data = vec![K::default(); vec.size()];
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                data[i] = vec.data[j].mul_add(self.data[i][j], data[i]);
+        self.try_mul_vec(vec).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies a matrix by a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with the result of the operation, or a `MatrixError::ShapeMismatch`
+    /// if the size of the vector differs from the number of columns of the matrix.
+    pub fn try_mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        if self.shape()[0] != vec.size() {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, self.shape()[0]],
+                found: vec.shape(),
+            });
+        }
+        let mut res: Vector<K> = Vector::zeros(self.rows);
+        for (i, row) in self.rows().enumerate() {
+            for j in 0..self.cols {
+                res[i] = vec[j].clone().mul_add(row[j].clone(), res[i].clone());
             }
         }
-        Vector { data }
+        Ok(res)
     }
 
     /// Multiplies a matrix by another matrix.
@@ -36,19 +53,47 @@ impl<
     /// # Returns
     ///
     /// A new matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `mat` differs from the number of columns of the
+    /// matrix.
     pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
-        let n = self.shape()[0];
-        let m = self.shape()[1];
-        let p = mat.shape()[0];
-        let mut data = vec![vec![K::default(); p]; m];
+        self.try_mul_mat(mat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies a matrix by another matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `mat` - The matrix to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix with the result of the operation, or a `MatrixError::ShapeMismatch`
+    /// if the number of rows of `mat` differs from the number of columns of the matrix.
+    pub fn try_mul_mat(&self, mat: &Matrix<K>) -> Result<Matrix<K>, MatrixError> {
+        if self.shape()[0] != mat.shape()[1] {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [mat.shape()[0], self.shape()[0]],
+                found: mat.shape(),
+            });
+        }
+        let n = self.cols;
+        let m = self.rows;
+        let p = mat.cols;
+        let mut res: Matrix<K> = Matrix::zeros(m, p);
         for i in 0..m {
-            for j in 0..p {
-                for k in 0..n {
-                    data[i][j] = mat.data[k][j].mul_add(self.data[i][k], data[i][j]);
+            let res_row = &mut res.data[i * p..(i + 1) * p];
+            for k in 0..n {
+                let a = &self.data[i * n + k];
+                let mat_row = &mat.data[k * p..(k + 1) * p];
+                for j in 0..p {
+                    res_row[j] = mat_row[j].clone().mul_add(a.clone(), res_row[j].clone());
                 }
             }
         }
-        Matrix { data }
+        Ok(res)
     }
 }
 
@@ -81,4 +126,34 @@ mod tests {
         let v = Matrix::from([[2., 1.], [4., 2.]]);
         assert_eq!(u.mul_mat(&v), Matrix::from([[-14., -7.], [44., 22.]]));
     }
+
+    #[test]
+    fn test_try_mul_vec() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(
+            u.try_mul_vec(&Vector::from([1., 0., 1.])),
+            Ok(Vector::from([4., 10.]))
+        );
+        assert_eq!(
+            u.try_mul_vec(&Vector::from([1., 0.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_mul_mat() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let v = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        assert_eq!(u.try_mul_mat(&v), Ok(Matrix::from([[4., 5.], [10., 11.]])));
+        assert_eq!(
+            u.try_mul_mat(&u),
+            Err(MatrixError::ShapeMismatch {
+                expected: [3, 3],
+                found: [3, 2]
+            })
+        );
+    }
 }
@@ -1,17 +1,35 @@
-use crate::matrix::Matrix;
+use crate::{error::MatrixError, matrix::Matrix, scalar::Scalar};
 
-impl<K: Copy + Default + std::ops::AddAssign> Matrix<K> {
+impl<K: Scalar> Matrix<K> {
     /// Computes the trace of a square matrix.
     ///
     /// # Returns
     ///
     /// The trace of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
     pub fn trace(&self) -> K {
-        let mut res = K::default();
+        self.try_trace().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the trace of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The trace of the matrix, or a `MatrixError::NotSquare` if the matrix is not square.
+    pub fn try_trace(&self) -> Result<K, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let mut res = K::zero();
         for i in 0..self.shape()[0] {
-            res += self.data[i][i];
+            res += self[(i, i)].clone();
         }
-        res
+        Ok(res)
     }
 }
 
@@ -28,4 +46,10 @@ mod tests {
         let u = Matrix::from([[-2., -8., 4.], [1., -23., 4.], [0., 6., 4.]]);
         assert_eq!(-21., u.trace());
     }
+
+    #[test]
+    fn test_try_trace() {
+        let u = Matrix::from([[2., -5., 0.], [4., 3., 7.]]);
+        assert_eq!(u.try_trace(), Err(MatrixError::NotSquare { shape: [3, 2] }));
+    }
 }
@@ -1,21 +1,15 @@
-use num::complex::ComplexFloat;
+use num::{complex::ComplexFloat, rational::Ratio};
 
-use crate::{matrix::Matrix, Complex};
+use crate::{matrix::Matrix, scalar::Scalar, Complex};
 
-impl<K: Copy + Default + std::ops::AddAssign + Conjugate> Matrix<K> {
+impl<K: Scalar + Conjugate> Matrix<K> {
     /// Computes the conjugate transpose of a matrix.
     ///
     /// # Returns
     ///
     /// The conjugate transpose of the matrix.
     pub fn transpose(&self) -> Matrix<K> {
-        let mut data = vec![vec![K::default(); self.shape()[1]]; self.shape()[0]];
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                data[i][j] = self.data[j][i].conjugate();
-            }
-        }
-        Matrix { data }
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)].conjugate())
     }
 }
 
@@ -47,6 +41,12 @@ impl Conjugate for Complex<f64> {
     }
 }
 
+impl<T: Clone + num::Integer> Conjugate for Ratio<T> {
+    fn conjugate(&self) -> Ratio<T> {
+        self.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,52 +1,68 @@
-use crate::{ex04::Modulus, matrix::Matrix};
+use crate::{
+    ex04::Modulus,
+    matrix::Matrix,
+    scalar::{Field, Scalar},
+};
 
-impl<
-        K: Copy
-            + std::fmt::Debug
-            + Default
-            + std::cmp::PartialOrd
-            + From<f32>
-            + Modulus
-            + std::ops::Div<Output = K>
-            + std::ops::Mul<Output = K>
-            + std::ops::SubAssign,
-    > Matrix<K>
-{
+/// A rule choosing the pivot of a column during Gaussian elimination.
+pub trait PivotStrategy<K> {
+    /// Finds the row holding the pivot of a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The matrix being eliminated.
+    /// * `col` - The column to find a pivot in.
+    /// * `from_row` - The first row that may hold the pivot.
+    ///
+    /// # Returns
+    ///
+    /// The index of the pivot row, or `None` if the column is zero from `from_row` down.
+    fn find_pivot(data: &Matrix<K>, col: usize, from_row: usize) -> Option<usize>;
+}
+
+/// Picks the entry of largest modulus, which keeps floating point elimination stable.
+pub struct PartialPivoting;
+
+/// Picks the first non-zero entry, which is all exact arithmetic needs.
+pub struct FirstNonZero;
+
+impl<K: Scalar + Modulus> PivotStrategy<K> for PartialPivoting {
+    fn find_pivot(data: &Matrix<K>, col: usize, from_row: usize) -> Option<usize> {
+        let mut max_row = from_row;
+        for i in from_row..data.rows {
+            if data[(i, col)].modulus() > data[(max_row, col)].modulus() {
+                max_row = i;
+            }
+        }
+        (!data[(max_row, col)].is_zero()).then_some(max_row)
+    }
+}
+
+impl<K: Scalar> PivotStrategy<K> for FirstNonZero {
+    fn find_pivot(data: &Matrix<K>, col: usize, from_row: usize) -> Option<usize> {
+        (from_row..data.rows).find(|&i| !data[(i, col)].is_zero())
+    }
+}
+
+impl<K: Field> Matrix<K> {
     /// Converts the matrix to its reduced row echelon form.
     ///
     /// # Returns
     ///
     /// A new matrix that is the row echelon form of the original matrix.
     pub fn row_echelon(&self) -> Matrix<K> {
-        let mut data = self.data.clone();
-        let mut offset_n: usize = 0;
-        let mut offset_m: usize = 0;
-        while offset_m < self.shape()[1] && offset_n < self.shape()[0] {
-            match switch_rows(&mut data, &mut offset_n, &mut offset_m, &mut 0) {
-                Ok(_) => {
-                    normalize_row(&mut data, offset_n, offset_m);
-                    remove_first_entries(&mut data, offset_n, offset_m);
-                    offset_m += 1;
-                    offset_n += 1;
-                }
-                Err(_) => continue,
-            };
-        }
-        offset_m = self.shape()[1] - 1;
-        offset_n = self.shape()[0] - 1;
-        while offset_m > 0 && offset_n > 0 {
-            match find_next_pivot(&mut data, &mut offset_n, &mut offset_m) {
-                Ok(_) => {
-                    remove_last_entries(&mut data, offset_n, offset_m);
-                    if offset_n != 0 && offset_m != 0 {
-                        offset_m -= 1;
-                        offset_n -= 1;
-                    }
-                }
-                Err(_) => break,
-            }
-        }
-        Matrix { data }
+        self.row_echelon_with::<K::Pivot>()
+    }
+
+    /// Converts the matrix to its reduced row echelon form with a given pivot strategy.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the row echelon form of the original matrix.
+    pub fn row_echelon_with<S: PivotStrategy<K>>(&self) -> Matrix<K> {
+        let mut data = self.clone();
+        gauss_jordan::<K, S>(&mut data, self.cols, 0.);
+        data
     }
 
     /// Converts the matrix to its row echelon form and counts the number of row switches.
@@ -59,11 +75,16 @@ impl<
     ///
     /// A new matrix that is the row echelon form of the original matrix.
     pub fn row_echelon_count(&self, switch_counter: &mut usize) -> Matrix<K> {
-        let mut data = self.data.clone();
+        let mut data = self.clone();
         let mut offset_n: usize = 0;
         let mut offset_m: usize = 0;
         while offset_m < self.shape()[1] && offset_n < self.shape()[0] {
-            match switch_rows(&mut data, &mut offset_n, &mut offset_m, switch_counter) {
+            match switch_rows::<K, K::Pivot>(
+                &mut data,
+                &mut offset_n,
+                &mut offset_m,
+                switch_counter,
+            ) {
                 Ok(_) => {
                     remove_first_entries(&mut data, offset_n, offset_m);
                     offset_m += 1;
@@ -72,177 +93,146 @@ impl<
                 Err(_) => continue,
             };
         }
-        Matrix { data }
+        data
     }
 }
 
-/// Switches the rows of a matrix to move the row with the largest element to the top.
+/// Brings a matrix to reduced row echelon form with Gauss-Jordan elimination.
+///
+/// Pivots are only searched for in the leading `pivot_cols` columns, the other ones being
+/// reduced along, and a column whose candidate pivot is negligible is treated as zero.
 ///
 /// # Arguments
 ///
 /// * `data` - A mutable reference to the matrix data.
-/// * `offset_n` - A mutable reference to the column offset.
-/// * `offset_m` - A mutable reference to the row offset.
-/// * `switch_counter` - A mutable reference to the row switch counter.
+/// * `pivot_cols` - The number of leading columns that may hold a pivot.
+/// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
 ///
 /// # Returns
 ///
-/// A Result containing `()` if the operation was successful, or a ZeroedColumnError if the column is all zeroes.
-fn switch_rows<K: Copy + Default + From<f32> + std::cmp::PartialOrd + Modulus>(
-    data: &mut Vec<Vec<K>>,
-    offset_n: &mut usize,
-    offset_m: &mut usize,
-    switch_counter: &mut usize,
-) -> Result<(), ZeroedColumnError> {
-    let max_row = find_max_row(data, offset_n, offset_m)?;
-    if max_row != *offset_m {
-        data.swap(*offset_m, max_row);
-        *switch_counter += 1;
+/// The columns holding the pivots, in increasing order, the first `pivots.len()` rows
+/// being the non-zero ones.
+pub(crate) fn gauss_jordan<K: Field, S: PivotStrategy<K>>(
+    data: &mut Matrix<K>,
+    pivot_cols: usize,
+    tolerance: f64,
+) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for j in 0..pivot_cols {
+        let row = pivots.len();
+        if row == data.rows {
+            break;
+        }
+        let pivot_row =
+            S::find_pivot(data, j, row).filter(|&i| !data[(i, j)].is_negligible(tolerance));
+        let Some(pivot_row) = pivot_row else {
+            for i in row..data.rows {
+                data[(i, j)] = K::zero();
+            }
+            continue;
+        };
+        data.swap_rows(row, pivot_row);
+        let pivot = data[(row, j)].inv();
+        for k in j..data.cols {
+            data[(row, k)] *= pivot.clone();
+        }
+        for i in (0..data.rows).filter(|&i| i != row) {
+            let factor = data[(i, j)].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for k in j..data.cols {
+                let value = data[(row, k)].clone() * factor.clone();
+                data[(i, k)] -= value;
+            }
+        }
+        pivots.push(j);
     }
-    Ok(())
+    pivots
 }
 
-/// Finds the row with the largest element in a given column.
+/// Switches the rows of a matrix to move the pivot chosen by a strategy to the top.
 ///
 /// # Arguments
 ///
 /// * `data` - A mutable reference to the matrix data.
 /// * `offset_n` - A mutable reference to the column offset.
 /// * `offset_m` - A mutable reference to the row offset.
+/// * `switch_counter` - A mutable reference to the row switch counter.
 ///
 /// # Returns
 ///
-/// A Result containing the index of the row with the largest element in the column, or a ZeroedColumnError if the column is all zeroes.
-fn find_max_row<K: Copy + Default + From<f32> + std::cmp::PartialOrd + Modulus>(
-    data: &mut Vec<Vec<K>>,
+/// A Result containing `()` if the operation was successful, or a ZeroedColumnError if the column is all zeroes.
+fn switch_rows<K: Field, S: PivotStrategy<K>>(
+    data: &mut Matrix<K>,
     offset_n: &mut usize,
     offset_m: &mut usize,
-) -> Result<usize, ZeroedColumnError> {
-    let mut max_row = *offset_m;
-    for i in *offset_m..data.len() {
-        if data[i][*offset_n].modulus() > data[max_row][*offset_n].modulus() {
-            max_row = i;
-        }
-    }
-    if max_row == *offset_m && data[max_row][*offset_n] == K::from(0.) {
+    switch_counter: &mut usize,
+) -> Result<(), ZeroedColumnError> {
+    let Some(pivot_row) = S::find_pivot(data, *offset_n, *offset_m) else {
         *offset_n += 1;
         return Err(ZeroedColumnError);
-    }
-    Ok(max_row)
+    };
+    switch_two_rows(data, *offset_m, pivot_row, switch_counter);
+    Ok(())
 }
 
-/// Normalizes the current pivot modifying the whole row.
+/// Swaps two rows of a matrix, counting the switch if the rows are distinct.
 ///
 /// # Arguments
 ///
 /// * `data` - A mutable reference to the matrix data.
-/// * `offset_n` - The column offset.
-/// * `offset_m` - The row offset.
-fn normalize_row<
-    K: Copy + Default + std::cmp::PartialEq + From<f32> + std::ops::Div<Output = K>,
->(
-    data: &mut Vec<Vec<K>>,
-    offset_n: usize,
-    offset_m: usize,
+/// * `a` - The index of the first row.
+/// * `b` - The index of the second row.
+/// * `switch_counter` - A mutable reference to the row switch counter.
+pub(crate) fn switch_two_rows<K>(
+    data: &mut Matrix<K>,
+    a: usize,
+    b: usize,
+    switch_counter: &mut usize,
 ) {
-    if data[offset_m][offset_n] != K::from(1.) {
-        let factor = data[offset_m][offset_n];
-        for i in offset_n..data[0].len() {
-            data[offset_m][i] = data[offset_m][i] / factor;
-        }
+    if a != b {
+        data.swap_rows(a, b);
+        *switch_counter += 1;
     }
 }
 
-/// Removes the first entries in the rows below the pivot.
+/// Applies the sign of a sequence of row switches to a determinant.
 ///
 /// # Arguments
 ///
-/// * `data` - A mutable reference to the matrix data.
-/// * `offset_n` - The column offset.
-/// * `offset_m` - The row offset.
-fn remove_first_entries<
-    K: Copy
-        + Default
-        + std::cmp::PartialEq
-        + From<f32>
-        + std::ops::Mul<Output = K>
-        + std::ops::Div<Output = K>
-        + std::ops::SubAssign,
->(
-    data: &mut Vec<Vec<K>>,
-    offset_n: usize,
-    offset_m: usize,
-) {
-    for i in offset_m + 1..data.len() {
-        if data[i][offset_n] == K::from(0.) {
-            continue;
-        }
-        let factor = data[offset_m][offset_n];
-        let row: Vec<K> = data[offset_m]
-            .clone()
-            .into_iter()
-            .map(|x| x * (data[i][offset_n] / factor))
-            .collect();
-        for j in offset_n..data[0].len() {
-            data[i][j] -= row[j];
-        }
-    }
-}
-
-/// Finds the next pivot in the matrix.
+/// * `value` - The determinant of the matrix once its rows have been switched.
+/// * `switch_counter` - The number of row switches.
 ///
-/// # Arguments
+/// # Returns
 ///
-/// * `data` - A mutable reference to the matrix data.
-/// * `offset_n` - A mutable reference to the column offset.
-/// * `offset_m` - A mutable reference to the row offset.
-fn find_next_pivot<K: Copy + Default + std::cmp::PartialEq + From<f32>>(
-    data: &mut Vec<Vec<K>>,
-    offset_n: &mut usize,
-    offset_m: &mut usize,
-) -> Result<(), ZeroedMatrixError> {
-    if let Some(new_offset_n) = data[*offset_m].iter().position(|x| *x == K::from(1.)) {
-        *offset_n = new_offset_n;
-        Ok(())
+/// The determinant of the matrix before its rows were switched.
+pub(crate) fn switch_sign<K: Scalar>(value: K, switch_counter: usize) -> K {
+    if switch_counter.is_multiple_of(2) {
+        value
     } else {
-        if *offset_m == 0 {
-            return Err(ZeroedMatrixError);
-        }
-        *offset_m -= 1;
-        find_next_pivot(data, offset_n, offset_m)
+        -value
     }
 }
 
-/// Removes the last entries in the rows above the pivot.
+/// Removes the first entries in the rows below the pivot.
 ///
 /// # Arguments
 ///
 /// * `data` - A mutable reference to the matrix data.
 /// * `offset_n` - The column offset.
 /// * `offset_m` - The row offset.
-fn remove_last_entries<
-    K: Copy
-        + Default
-        + std::cmp::PartialEq
-        + From<f32>
-        + std::ops::Mul<Output = K>
-        + std::ops::SubAssign,
->(
-    data: &mut Vec<Vec<K>>,
-    offset_n: usize,
-    offset_m: usize,
-) {
-    for i in (0..offset_m).rev() {
-        if data[i][offset_n] == K::from(0.) {
+fn remove_first_entries<K: Field>(data: &mut Matrix<K>, offset_n: usize, offset_m: usize) {
+    let pivot = data[(offset_m, offset_n)].clone();
+    for i in offset_m + 1..data.rows {
+        let entry = data[(i, offset_n)].clone();
+        if entry.is_zero() {
             continue;
         }
-        let row: Vec<K> = data[offset_m]
-            .clone()
-            .into_iter()
-            .map(|x| x * data[i][offset_n])
-            .collect();
-        for j in offset_n..data[0].len() {
-            data[i][j] -= row[j];
+        let factor = entry / pivot.clone();
+        for j in offset_n..data.cols {
+            let value = data[(offset_m, j)].clone() * factor.clone();
+            data[(i, j)] -= value;
         }
     }
 }
@@ -257,19 +247,11 @@ impl std::fmt::Display for ZeroedColumnError {
     }
 }
 
-/// An error type for when all elements in a matrix are zeroes.
-#[derive(Debug, Clone)]
-struct ZeroedMatrixError;
-
-impl std::fmt::Display for ZeroedMatrixError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "All elements in matrix are zeroes")
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_matrix_approx_eq;
+    use num::rational::Ratio;
 
     #[test]
     fn test_row_echelon() {
@@ -287,13 +269,43 @@ mod tests {
             [4., 2.5, 20., 4., -4.],
             [8., 5., 1., 4., 17.],
         ]);
-        assert_eq!(
+        assert_matrix_approx_eq!(
             u.row_echelon(),
             Matrix::from([
                 [1., 0.625, 0., 0., -12.1666667],
                 [0., 0., 1., 0., -3.6666667],
                 [0., 0., 0., 1., 29.5]
+            ]),
+            abs = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_row_echelon_rational() {
+        let r = Ratio::new;
+        let u = Matrix::from([
+            [r(8, 1), r(5, 1), r(-2, 1), r(4, 1), r(28, 1)],
+            [r(4, 1), r(5, 2), r(20, 1), r(4, 1), r(-4, 1)],
+            [r(8, 1), r(5, 1), r(1, 1), r(4, 1), r(17, 1)],
+        ]);
+        assert_eq!(
+            u.row_echelon(),
+            Matrix::from([
+                [r(1, 1), r(5, 8), r(0, 1), r(0, 1), r(-73, 6)],
+                [r(0, 1), r(0, 1), r(1, 1), r(0, 1), r(-11, 3)],
+                [r(0, 1), r(0, 1), r(0, 1), r(1, 1), r(59, 2)]
             ])
         );
     }
+
+    #[test]
+    fn test_row_echelon_with() {
+        let u = Matrix::from([[1., 2.], [3., 4.]]);
+        let expected = Matrix::from([[1., 0.], [0., 1.]]);
+        assert_eq!(u.row_echelon_with::<FirstNonZero>(), expected);
+        assert_eq!(u.row_echelon_with::<PartialPivoting>(), expected);
+        let u = Matrix::from([[0., 1.], [0., 0.], [2., 0.]]);
+        assert_eq!(FirstNonZero::find_pivot(&u, 0, 0), Some(2));
+        assert_eq!(PartialPivoting::find_pivot(&u, 1, 1), None);
+    }
 }
@@ -1,44 +1,39 @@
-use crate::{ex04::Modulus, matrix::Matrix};
+use crate::{error::MatrixError, matrix::Matrix, scalar::Field};
 
-impl<
-        K: Copy
-            + std::fmt::Debug
-            + Default
-            + std::cmp::PartialOrd
-            + From<f32>
-            + Modulus
-            + std::ops::Neg<Output = K>
-            + std::ops::Div<Output = K>
-            + std::ops::Mul<Output = K>
-            + std::ops::SubAssign
-            + std::ops::MulAssign,
-    > Matrix<K>
-{
+impl<K: Field> Matrix<K> {
     /// Calculates the determinant of the matrix.
     ///
     /// # Returns
     ///
     /// The determinant of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
     pub fn determinant(&self) -> K {
-        let mut switch_counter = 0;
-        let row_echelon_form = self.row_echelon_count(&mut switch_counter);
-        let mut determinant = K::from(1.);
-        for i in 0..self.shape()[0] {
-            if row_echelon_form.data[i][i] == K::default() {
-                return K::default();
-            }
-            determinant *= row_echelon_form.data[i][i];
-        }
-        if switch_counter % 2 != 0 {
-            determinant = -determinant;
+        self.try_determinant().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Calculates the determinant of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The determinant of the matrix, or a `MatrixError::NotSquare` if the matrix is not
+    /// square.
+    pub fn try_determinant(&self) -> Result<K, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
         }
-        determinant
+        Ok(self.try_lu()?.determinant())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num::{bigint::BigInt, rational::Ratio};
 
     #[test]
     fn test_determinant() {
@@ -56,4 +51,28 @@ mod tests {
         ]);
         assert_eq!(1032., u.determinant());
     }
+
+    #[test]
+    fn test_try_determinant() {
+        let u = Matrix::from([[8., 5., -2.], [4., 7., 20.]]);
+        assert_eq!(
+            u.try_determinant(),
+            Err(MatrixError::NotSquare { shape: [3, 2] })
+        );
+        let u = Matrix::from([[2., 0.], [0., 2.]]);
+        assert_eq!(u.try_determinant(), Ok(4.));
+    }
+
+    #[test]
+    fn test_determinant_rational() {
+        let u = Matrix::from_fn(4, 4, |i, j| Ratio::new(1i64, (i + j + 1) as i64));
+        assert_eq!(u.determinant(), Ratio::new(1, 6048000));
+        let u = Matrix::from_fn(4, 4, |i, j| {
+            Ratio::new(BigInt::from(1), BigInt::from(i + j + 1))
+        });
+        assert_eq!(
+            u.determinant(),
+            Ratio::new(BigInt::from(1), BigInt::from(6048000))
+        );
+    }
 }
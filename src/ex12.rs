@@ -1,50 +1,78 @@
-use crate::{ex04::Modulus, matrix::Matrix};
+use crate::{error::MatrixError, matrix::Matrix, scalar::Field, vector::Vector};
 
-impl<
-        K: Copy
-            + std::fmt::Debug
-            + Default
-            + std::cmp::PartialOrd
-            + From<f32>
-            + Modulus
-            + std::ops::Div<Output = K>
-            + std::ops::Mul<Output = K>
-            + std::ops::SubAssign,
-    > Matrix<K>
-{
+impl<K: Field> Matrix<K> {
     /// Calculates the inverse of the matrix.
     ///
     /// # Returns
     ///
     /// The inverse of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or is singular.
     pub fn inverse(&self) -> Matrix<K> {
-        let mut data = vec![];
-        let n = self.shape()[0];
-        for i in 0..n {
-            data.push(
-                [
-                    self.data[i].clone(),
-                    (0..n)
-                        .map(|j| if i == j { K::from(1.) } else { K::from(0.) })
-                        .collect(),
-                ]
-                .concat(),
-            );
+        self.try_inverse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Calculates the inverse of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrix, a `MatrixError::NotSquare` if the matrix is not square,
+    /// or a `MatrixError::Singular` if the matrix has no inverse.
+    pub fn try_inverse(&self) -> Result<Matrix<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
         }
-        println!("{:?}", data);
-        let m = Matrix::from(data).row_echelon();
-        println!("{}", m);
-        let mut inverse_data = vec![vec![K::default(); n]; n];
-        for i in 0..n {
-            inverse_data[i] = m.data[i][n..].to_vec();
+        self.try_lu()?.try_inverse()
+    }
+
+    /// Solves the linear system `self * x = b` for a square and invertible matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    ///
+    /// # Returns
+    ///
+    /// The unique solution of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or is singular, or if the size of `b` differs
+    /// from the number of rows of the matrix.
+    pub fn solve_unique(&self, b: &Vector<K>) -> Vector<K> {
+        self.try_solve_unique(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `self * x = b` for a square and invertible matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    ///
+    /// # Returns
+    ///
+    /// The unique solution of the system, a `MatrixError::NotSquare` if the matrix is not
+    /// square, a `MatrixError::ShapeMismatch` if the size of `b` differs from the number
+    /// of rows of the matrix, or a `MatrixError::Singular` if the solution is not unique.
+    pub fn try_solve_unique(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
         }
-        Matrix::from(inverse_data)
+        self.try_lu()?.try_solve(b)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_matrix_approx_eq;
+    use num::{bigint::BigInt, rational::Ratio};
 
     #[test]
     fn test_inverse() {
@@ -59,13 +87,70 @@ mod tests {
             Matrix::from([[0.5, 0., 0.], [0., 0.5, 0.], [0., 0., 0.5]])
         );
         let u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
-        assert_eq!(
+        assert_matrix_approx_eq!(
             u.inverse(),
             Matrix::from([
                 [0.649425287, 0.097701149, -0.655172414],
                 [-0.781609195, -0.126436782, 0.965517241],
                 [0.143678161, 0.074712644, -0.206896552]
+            ]),
+            abs = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_try_inverse() {
+        let u = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(u.try_inverse(), Err(MatrixError::Singular));
+        let u = Matrix::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        assert_eq!(u.try_inverse(), Err(MatrixError::Singular));
+        let u = Matrix::from([[1., 2., 3.], [2., 4., 5.]]);
+        assert_eq!(
+            u.try_inverse(),
+            Err(MatrixError::NotSquare { shape: [3, 2] })
+        );
+    }
+
+    #[test]
+    fn test_inverse_rational() {
+        let r = Ratio::new;
+        let u = Matrix::from([
+            [r(8, 1), r(5, 1), r(-2, 1)],
+            [r(4, 1), r(7, 1), r(20, 1)],
+            [r(7, 1), r(6, 1), r(1, 1)],
+        ]);
+        assert_eq!(
+            u.inverse(),
+            Matrix::from([
+                [r(113, 174), r(17, 174), r(-19, 29)],
+                [r(-68, 87), r(-11, 87), r(28, 29)],
+                [r(25, 174), r(13, 174), r(-6, 29)]
             ])
         );
+        let u = Matrix::from_fn(5, 5, |i, j| {
+            Ratio::new(BigInt::from(1), BigInt::from(i + j + 1))
+        });
+        assert_eq!(&u * &u.inverse(), Matrix::identity(5));
+    }
+
+    #[test]
+    fn test_solve_unique() {
+        let u = Matrix::from([[2., 1.], [1., 3.]]);
+        assert_eq!(
+            u.solve_unique(&Vector::from([3., 5.])),
+            Vector::from([0.8, 1.4])
+        );
+        assert_eq!(
+            u.try_solve_unique(&Vector::from([1., 2., 3.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+        let u = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(
+            u.try_solve_unique(&Vector::from([1., 2.])),
+            Err(MatrixError::Singular)
+        );
     }
 }
@@ -1,40 +1,24 @@
-use crate::{ex04::Modulus, matrix::Matrix};
+use crate::{matrix::Matrix, scalar::Field};
 
-impl<
-        K: Copy
-            + std::fmt::Debug
-            + Default
-            + std::cmp::PartialOrd
-            + From<f32>
-            + Modulus
-            + std::ops::Div<Output = K>
-            + std::ops::Mul<Output = K>
-            + std::ops::SubAssign,
-    > Matrix<K>
-{
+impl<K: Field> Matrix<K> {
     /// Calculates the rank of the matrix.
     ///
+    /// Entries are treated as zero below the default tolerance of the matrix, so that
+    /// rounding errors do not make a rank deficient floating point matrix look full rank.
+    ///
     /// # Returns
     ///
     /// The rank of the matrix.
     pub fn rank(&self) -> usize {
-        let row_echelon_form = self.row_echelon();
-        let mut rank = 0;
-        for i in 0..self.shape()[1] {
-            for j in 0..self.shape()[0] {
-                if row_echelon_form.data[i][j] != K::default() {
-                    rank += 1;
-                    break;
-                }
-            }
-        }
-        rank
+        self.row_echelon_pivots(self.default_tolerance()).1.len()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vector::Vector;
+    use num::rational::Ratio;
 
     #[test]
     fn test_rank() {
@@ -44,5 +28,16 @@ mod tests {
         assert_eq!(2, u.rank());
         let u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.], [21., 18., 7.]]);
         assert_eq!(3, u.rank());
+        let u = Matrix::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        assert_eq!(2, u.rank());
+    }
+
+    #[test]
+    fn test_rank_rational() {
+        let r = Ratio::new;
+        let u = Vector::from([r(1, 3), r(1, 7), r(1, 11)]);
+        let v = Vector::from([r(1, 5), r(1, 9), r(1, 13)]);
+        let w = &u._scl(r(1, 7)) + &v._scl(r(3, 11));
+        assert_eq!(2, Matrix::from_rows(&[u, v, w]).unwrap().rank());
     }
 }
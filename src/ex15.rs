@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::{
-        ex01::linear_combination, ex02::lerp, ex05::angle_cos, ex06::cross_product, matrix::Matrix,
-        vector::Vector, Complex,
+        assert_matrix_approx_eq, ex01::linear_combination, ex02::lerp, ex05::angle_cos,
+        ex06::cross_product, matrix::Matrix, vector::Vector, Complex,
     };
 
     #[test]
@@ -199,9 +199,9 @@ mod tests {
             Complex::from(2.),
             Complex::from(3.),
         ]);
-        assert_eq!(u.norm(), 3.74165738);
+        assert_eq!(u.norm(), 3.7416573867739413);
         let u = Vector::from(vec![Complex::from(-1.), Complex::from(-2.)]);
-        assert_eq!(u.norm(), 2.236067977);
+        assert_eq!(u.norm(), 2.23606797749979);
     }
 
     #[test]
@@ -240,7 +240,7 @@ mod tests {
         let v = Vector::from(vec![Complex::from(4.), Complex::from(2.)]);
         assert_eq!(angle_cos(&u, &v), Complex::from(1.));
 
-        let u = Vector::from(vec![
+        let u: Vector<Complex<f32>> = Vector::from(vec![
             Complex::from(1.),
             Complex::from(2.),
             Complex::from(3.),
@@ -540,7 +540,7 @@ mod tests {
             [Complex::from(4.), Complex::from(7.), Complex::from(20.)],
             [Complex::from(7.), Complex::from(6.), Complex::from(1.)],
         ]);
-        assert_eq!(
+        assert_matrix_approx_eq!(
             u.inverse(),
             Matrix::from([
                 [
@@ -558,7 +558,8 @@ mod tests {
                     Complex::from(0.074712644),
                     Complex::from(-0.206896552)
                 ]
-            ])
+            ]),
+            abs = 1e-6
         );
     }
 
@@ -0,0 +1,478 @@
+use num_traits::{Float, NumCast, ToPrimitive, Zero};
+
+use crate::{error::MatrixError, matrix::Matrix, scalar::ComplexField};
+
+/// The coefficients of the degree 13 Padé approximant of the exponential.
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// The largest 1-norm for which the degree 13 Padé approximant of the exponential is
+/// accurate to double precision.
+const THETA_13: f64 = 5.371920351148152;
+
+/// The nodes and weights of the 8-point Gauss-Legendre rule on `[-1, 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [
+    (-0.9602898564975363, 0.1012285362903763),
+    (-0.7966664774136267, 0.2223810344533745),
+    (-0.525532409916329, 0.3137066458778873),
+    (-0.1834346424956498, 0.362683783378362),
+    (0.1834346424956498, 0.362683783378362),
+    (0.525532409916329, 0.3137066458778873),
+    (0.7966664774136267, 0.2223810344533745),
+    (0.9602898564975363, 0.1012285362903763),
+];
+
+/// The number of Denman-Beavers iterations `sqrtm` allows before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// The number of square roots `logm` allows before giving up.
+const MAX_SQUARE_ROOTS: usize = 64;
+
+impl<K: ComplexField> Matrix<K> {
+    /// Computes the exponential of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The exponential of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn expm(&self) -> Matrix<K> {
+        self.try_expm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the exponential of a square matrix with scaling and squaring.
+    ///
+    /// The matrix is divided by a power of two until its 1-norm is small enough for the
+    /// degree 13 Padé approximant, whose result is then squared back.
+    ///
+    /// # Returns
+    ///
+    /// The exponential of the matrix, or a `MatrixError::NotSquare` if the matrix is not
+    /// square.
+    pub fn try_expm(&self) -> Result<Matrix<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let norm = norm_1(self);
+        let theta = from_f64::<K>(THETA_13).real();
+        let squarings = if norm > theta {
+            (norm / theta).log2().ceil().to_usize().unwrap_or(0)
+        } else {
+            0
+        };
+        let a = self._scl(from_f64::<K>(0.5.powi(squarings as i32)));
+        let identity = Matrix::identity(self.rows);
+        let a2 = a.mul_mat(&a);
+        let a4 = a2.mul_mat(&a2);
+        let a6 = a4.mul_mat(&a2);
+        let b = |i: usize| PADE_13[i];
+        let u = a.mul_mat(&combination(&[
+            (
+                &a6.mul_mat(&combination(&[(&a6, b(13)), (&a4, b(11)), (&a2, b(9))])),
+                1.,
+            ),
+            (&a6, b(7)),
+            (&a4, b(5)),
+            (&a2, b(3)),
+            (&identity, b(1)),
+        ]));
+        let v = combination(&[
+            (
+                &a6.mul_mat(&combination(&[(&a6, b(12)), (&a4, b(10)), (&a2, b(8))])),
+                1.,
+            ),
+            (&a6, b(6)),
+            (&a4, b(4)),
+            (&a2, b(2)),
+            (&identity, b(0)),
+        ]);
+        let mut res = (&v - &u).try_inverse()?.mul_mat(&(&v + &u));
+        for _ in 0..squarings {
+            res = res.mul_mat(&res);
+        }
+        Ok(res)
+    }
+
+    /// Computes the principal square root of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The principal square root of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, if it is singular but not zero, or if the
+    /// iteration does not converge.
+    pub fn sqrtm(&self) -> Matrix<K> {
+        self.try_sqrtm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the principal square root of a square matrix with the Denman-Beavers
+    /// iteration.
+    ///
+    /// The principal square root is the one whose eigenvalues have a positive real part.
+    /// It exists when no eigenvalue lies on the closed negative real axis, and is real for
+    /// a real matrix. The iteration needs an invertible matrix, so the zero matrix, whose
+    /// square root is itself, is the only singular matrix handled.
+    ///
+    /// # Returns
+    ///
+    /// The principal square root of the matrix, a `MatrixError::NotSquare` if the matrix
+    /// is not square, a `MatrixError::Singular` if it is singular but not zero, or a
+    /// `MatrixError::NoConvergence` if the iteration does not converge, which happens
+    /// when a real matrix has a negative eigenvalue.
+    pub fn try_sqrtm(&self) -> Result<Matrix<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        if self.iter().all(|x| x.is_zero()) {
+            return Ok(self.clone());
+        }
+        denman_beavers(self)
+    }
+
+    /// Computes the principal logarithm of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The principal logarithm of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, if it is singular, or if the iteration does
+    /// not converge.
+    pub fn logm(&self) -> Matrix<K> {
+        self.try_logm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the principal logarithm of a square matrix with inverse scaling and
+    /// squaring.
+    ///
+    /// Square roots are taken until the matrix is close to the identity, where the
+    /// logarithm is evaluated by Gauss-Legendre quadrature of its integral form, and the
+    /// result is multiplied back by a power of two.
+    ///
+    /// # Returns
+    ///
+    /// The principal logarithm of the matrix, a `MatrixError::NotSquare` if the matrix is
+    /// not square, a `MatrixError::Singular` if it is singular, or a
+    /// `MatrixError::NoConvergence` if the square roots do not converge, which happens
+    /// when a real matrix has a negative eigenvalue.
+    pub fn try_logm(&self) -> Result<Matrix<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let identity = Matrix::identity(self.rows);
+        let threshold = from_f64::<K>(0.25).real();
+        let mut x = self.clone();
+        let mut roots = 0;
+        while norm_1(&(&x - &identity)) > threshold {
+            if roots == MAX_SQUARE_ROOTS {
+                return Err(MatrixError::NoConvergence { iterations: roots });
+            }
+            x = denman_beavers(&x)?;
+            roots += 1;
+        }
+        let x = &x - &identity;
+        let mut res = Matrix::zeros(self.rows, self.rows);
+        for (node, weight) in GAUSS_LEGENDRE {
+            let node = from_f64::<K>((1. + node) / 2.);
+            let inverse = (&identity + &x._scl(node)).try_inverse()?;
+            res += x.mul_mat(&inverse)._scl(from_f64::<K>(weight / 2.));
+        }
+        Ok(res._scl(from_f64::<K>(2f64.powi(roots as i32))))
+    }
+
+    /// Raises a square matrix to a real power.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The exponent.
+    ///
+    /// # Returns
+    ///
+    /// The matrix to the power of `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or in the cases listed by `try_powm`.
+    pub fn powm(&self, p: K::Real) -> Matrix<K> {
+        self.try_powm(p).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Raises a square matrix to a real power.
+    ///
+    /// Integer powers are computed exactly by repeated squaring, of the inverse for
+    /// negative ones, and the others as the exponential of `p` times the principal
+    /// logarithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The exponent.
+    ///
+    /// # Returns
+    ///
+    /// The matrix to the power of `p`, a `MatrixError::NotSquare` if the matrix is not
+    /// square, or the errors of `try_inverse` and `try_logm`.
+    pub fn try_powm(&self, p: K::Real) -> Result<Matrix<K>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        if let Some(exp) = (p.fract().is_zero()).then(|| p.abs().to_u64()).flatten() {
+            let mut base = if p < K::Real::zero() {
+                self.try_inverse()?
+            } else {
+                self.clone()
+            };
+            let mut res = Matrix::identity(self.rows);
+            let mut exp = exp;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    res = res.mul_mat(&base);
+                }
+                base = base.mul_mat(&base);
+                exp >>= 1;
+            }
+            return Ok(res);
+        }
+        self.try_logm()?._scl(K::from_real(p)).try_expm()
+    }
+}
+
+/// Converts a double precision constant to a scalar.
+///
+/// # Arguments
+///
+/// * `x` - The constant.
+///
+/// # Returns
+///
+/// The real scalar closest to `x`.
+fn from_f64<K: ComplexField>(x: f64) -> K {
+    K::from_real(NumCast::from(x).unwrap_or_else(K::Real::nan))
+}
+
+/// Computes the principal square root of a square matrix with the Denman-Beavers
+/// iteration.
+///
+/// # Arguments
+///
+/// * `a` - The square matrix.
+///
+/// # Returns
+///
+/// The principal square root of the matrix, a `MatrixError::Singular` if the matrix is
+/// singular, or a `MatrixError::NoConvergence` if the iteration does not converge. An
+/// iterate becoming singular counts as a failure to converge, as happens when a real
+/// matrix has a negative eigenvalue.
+fn denman_beavers<K: ComplexField>(a: &Matrix<K>) -> Result<Matrix<K>, MatrixError> {
+    let half = from_f64::<K>(0.5);
+    let threshold = K::Real::epsilon().sqrt();
+    let mut y = a.clone();
+    let mut z = Matrix::identity(a.rows);
+    let mut converged = false;
+    for k in 0..MAX_ITERATIONS {
+        let inverses = z
+            .try_inverse()
+            .and_then(|z_inverse| Ok((z_inverse, y.try_inverse()?)));
+        let (z_inverse, y_inverse) = match inverses {
+            Err(MatrixError::Singular) if k > 0 => {
+                return Err(MatrixError::NoConvergence { iterations: k });
+            }
+            inverses => inverses?,
+        };
+        let next = (&y + &z_inverse)._scl(half.clone());
+        z = (&z + &y_inverse)._scl(half.clone());
+        let delta = norm_1(&(&next - &y));
+        y = next;
+        if converged {
+            return Ok(y);
+        }
+        converged = delta <= threshold * norm_1(&y);
+    }
+    Err(MatrixError::NoConvergence {
+        iterations: MAX_ITERATIONS,
+    })
+}
+
+/// Computes the operator 1-norm of a matrix.
+///
+/// # Arguments
+///
+/// * `m` - The matrix.
+///
+/// # Returns
+///
+/// The largest 1-norm of the columns of the matrix.
+fn norm_1<K: ComplexField>(m: &Matrix<K>) -> K::Real {
+    m.cols()
+        .map(|col| col.norm_1())
+        .fold(K::Real::zero(), Float::max)
+}
+
+/// Computes a linear combination of matrices with constant coefficients.
+///
+/// # Arguments
+///
+/// * `terms` - The matrices, all of the same shape, and their coefficients.
+///
+/// # Returns
+///
+/// The sum of the matrices scaled by their coefficients.
+fn combination<K: ComplexField>(terms: &[(&Matrix<K>, f64)]) -> Matrix<K> {
+    let [cols, rows] = terms[0].0.shape();
+    terms
+        .iter()
+        .fold(Matrix::zeros(rows, cols), |res, (m, coef)| {
+            &res + &m._scl(from_f64::<K>(*coef))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matrix_approx_eq, Complex};
+
+    #[test]
+    fn test_expm() {
+        let e = std::f64::consts::E;
+        assert_matrix_approx_eq!(Matrix::<f64>::zeros(3, 3).expm(), Matrix::identity(3));
+        assert_matrix_approx_eq!(
+            Matrix::from([[1., 0.], [0., 2.]]).expm(),
+            Matrix::from([[e, 0.], [0., e * e]])
+        );
+        assert_matrix_approx_eq!(
+            Matrix::from([[0., 1.], [0., 0.]]).expm(),
+            Matrix::from([[1., 1.], [0., 1.]])
+        );
+        let (sin, cos) = 10f64.sin_cos();
+        assert_matrix_approx_eq!(
+            Matrix::from([[0., 10.], [-10., 0.]]).expm(),
+            Matrix::from([[cos, sin], [-sin, cos]])
+        );
+        let (a, b) = ((-1f64).exp(), (-17f64).exp());
+        assert_matrix_approx_eq!(
+            Matrix::from([[-49., 24.], [-64., 31.]]).expm(),
+            Matrix::from([
+                [3. * b - 2. * a, 1.5 * (a - b)],
+                [4. * (b - a), 3. * a - 2. * b]
+            ])
+        );
+        let pi = Complex(num::Complex::new(0., std::f64::consts::PI));
+        let minus_one = Complex::from(-1.);
+        assert_matrix_approx_eq!(
+            (&Matrix::<Complex<f64>>::identity(2) * pi).expm(),
+            &Matrix::identity(2) * minus_one
+        );
+        assert_eq!(
+            Matrix::from([[1., 2.]]).try_expm().err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+    }
+
+    #[test]
+    fn test_sqrtm() {
+        assert_matrix_approx_eq!(
+            Matrix::from([[4., 0.], [0., 9.]]).sqrtm(),
+            Matrix::from([[2., 0.], [0., 3.]])
+        );
+        assert_matrix_approx_eq!(
+            Matrix::from([[33., 24.], [48., 57.]]).sqrtm(),
+            Matrix::from([[5., 2.], [4., 7.]])
+        );
+        let a = Matrix::from([[1., 2., 0.], [0., 3., 1.], [1., 0., 4.]]);
+        let root = a.sqrtm();
+        assert_matrix_approx_eq!(root.mul_mat(&root), a);
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        assert_matrix_approx_eq!(
+            Matrix::from([[c(0., 2.), c(1., 0.)], [c(0., 0.), c(-3., 4.)]]).sqrtm(),
+            Matrix::from([[c(1., 1.), c(2. / 13., -3. / 13.)], [c(0., 0.), c(1., 2.)]])
+        );
+        assert_eq!(
+            Matrix::from([[-4.]]).try_sqrtm().err(),
+            Some(MatrixError::NoConvergence { iterations: 100 })
+        );
+        assert_eq!(
+            Matrix::from([[-1.]]).try_sqrtm().err(),
+            Some(MatrixError::NoConvergence { iterations: 1 })
+        );
+        assert_eq!(Matrix::<f64>::zeros(2, 2).sqrtm(), Matrix::zeros(2, 2));
+        assert_eq!(
+            Matrix::from([[0., 0.], [0., 1.]]).try_sqrtm().err(),
+            Some(MatrixError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_logm() {
+        let e = std::f64::consts::E;
+        assert_matrix_approx_eq!(Matrix::<f64>::identity(3).logm(), Matrix::zeros(3, 3));
+        assert_matrix_approx_eq!(
+            Matrix::from([[e, 0.], [0., e * e]]).logm(),
+            Matrix::from([[1., 0.], [0., 2.]])
+        );
+        let b = Matrix::from([[0.5, 1., 0.], [-0.25, 0.1, 0.3], [0., 0.2, -0.4]]);
+        assert_matrix_approx_eq!(b.expm().logm(), b, abs = 1e-10);
+        let a = Matrix::from([[1., 2., 0.], [0., 3., 1.], [1., 0., 4.]]);
+        assert_matrix_approx_eq!(a.logm().expm(), a, abs = 1e-10);
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        assert_matrix_approx_eq!(
+            Matrix::from([[c(0., 2.)]]).logm(),
+            Matrix::from([[c(2f64.ln(), std::f64::consts::FRAC_PI_2)]])
+        );
+        assert_eq!(
+            Matrix::from([[0., 0.], [0., 1.]]).try_logm().err(),
+            Some(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::<f64>::zeros(1, 1).try_logm().err(),
+            Some(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::from([[-1.]]).try_logm().err(),
+            Some(MatrixError::NoConvergence { iterations: 1 })
+        );
+    }
+
+    #[test]
+    fn test_powm() {
+        let a = Matrix::from([[2., 1.], [1., 3.]]);
+        assert_eq!(a.powm(3.), a.mul_mat(&a).mul_mat(&a));
+        assert_eq!(a.powm(0.), Matrix::identity(2));
+        assert_matrix_approx_eq!(a.powm(-2.), a.mul_mat(&a).inverse());
+        assert_matrix_approx_eq!(a.powm(0.5), a.sqrtm());
+        assert_matrix_approx_eq!(a.powm(1.5), a.mul_mat(&a.sqrtm()), abs = 1e-10);
+        assert_eq!(
+            Matrix::from([[1., 1.], [1., 1.]]).try_powm(-1.),
+            Err(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::from([[0., 1.], [0., 0.]]).powm(2.),
+            Matrix::zeros(2, 2)
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use num::complex::ComplexFloat;
-
+pub mod approx;
+pub mod bareiss;
+pub mod decomposition;
+pub mod error;
 pub mod ex00;
 pub mod ex01;
 pub mod ex02;
@@ -16,8 +18,16 @@ pub mod ex12;
 pub mod ex13;
 pub mod ex14;
 pub mod ex15;
+pub mod functions;
 pub mod matrix;
+pub mod modular;
+pub mod scalar;
+pub mod smatrix;
+pub mod solvers;
+pub mod subspace;
+pub mod svector;
 pub mod vector;
+pub mod view;
 
 pub trait Equals {
     fn equals(&self, v: &Self) -> bool;
@@ -28,25 +38,31 @@ pub struct Complex<K>(num::Complex<K>);
 
 impl Equals for f32 {
     fn equals(&self, v: &Self) -> bool {
-        (*self - *v).abs() < 1e-6
+        approx::ApproxEq::approx_eq(self, v)
     }
 }
 
 impl Equals for f64 {
     fn equals(&self, v: &Self) -> bool {
-        (*self - *v).abs() < 1e-6
+        approx::ApproxEq::approx_eq(self, v)
+    }
+}
+
+impl<T: Clone + num::Integer> Equals for num::rational::Ratio<T> {
+    fn equals(&self, v: &Self) -> bool {
+        self == v
     }
 }
 
 impl Equals for Complex<f64> {
     fn equals(&self, v: &Self) -> bool {
-        (self.0.re() - v.0.re()).abs() < 1e-6 && (self.0.im() - v.0.im()).abs() < 1e-6
+        approx::ApproxEq::approx_eq(self, v)
     }
 }
 
 impl Equals for Complex<f32> {
     fn equals(&self, v: &Self) -> bool {
-        (self.0.re() - v.0.re()).abs() < 1e-6 && (self.0.im() - v.0.im()).abs() < 1e-6
+        approx::ApproxEq::approx_eq(self, v)
     }
 }
 
@@ -78,6 +94,38 @@ impl PartialOrd for Complex<f64> {
     }
 }
 
+impl<K: Copy> Complex<K> {
+    /// Returns the real part of the number.
+    ///
+    /// # Returns
+    ///
+    /// The real part of the number.
+    pub fn re(&self) -> K {
+        self.0.re
+    }
+
+    /// Returns the imaginary part of the number.
+    ///
+    /// # Returns
+    ///
+    /// The imaginary part of the number.
+    pub fn im(&self) -> K {
+        self.0.im
+    }
+}
+
+impl<K> From<num::Complex<K>> for Complex<K> {
+    fn from(z: num::Complex<K>) -> Self {
+        Complex(z)
+    }
+}
+
+impl<K> From<Complex<K>> for num::Complex<K> {
+    fn from(z: Complex<K>) -> Self {
+        z.0
+    }
+}
+
 impl From<f32> for Complex<f32> {
     fn from(f: f32) -> Self {
         Complex(num::Complex::new(f, 0.))
@@ -190,6 +238,21 @@ where
     }
 }
 
+impl<K> std::ops::DivAssign for Complex<K>
+where
+    K: Copy
+        + num_traits::Num
+        + std::ops::AddAssign
+        + std::ops::SubAssign
+        + std::ops::MulAssign
+        + std::ops::DivAssign
+        + std::ops::RemAssign,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
 impl<K> std::ops::Neg for Complex<K>
 where
     K: Copy + num_traits::Num + std::ops::Neg<Output = K>,
@@ -233,3 +296,12 @@ where
         Complex(self.0.pow(rhs))
     }
 }
+
+impl<K> num_traits::One for Complex<K>
+where
+    K: Copy + num_traits::Num,
+{
+    fn one() -> Self {
+        Complex(num::Complex::new(K::one(), K::zero()))
+    }
+}
@@ -231,6 +231,6 @@ fn ex13() {
 fn ex14() {
     let m = projection(f32::consts::PI / 4., 16. / 9., 1., 50.);
     println!("{}", m);
-    let representation = m.to_string().replace(&['[', ']'], "");
+    let representation = m.to_string().replace(['[', ']'], "");
     fs::write("proj", representation).expect("Could not write to file");
 }
@@ -1,10 +1,18 @@
-use crate::Equals;
+use num::{bigint::BigInt, rational::Ratio};
 
-/// A generic Matrix struct that holds a 2D vector of data.
+use crate::{
+    error::MatrixError, scalar::Scalar, vector::Vector, view::MatrixView, Complex, Equals,
+};
+
+/// A generic Matrix struct that holds its elements in a single row-major buffer.
 #[derive(Clone, Debug)]
 pub struct Matrix<K> {
-    /// The data of the Matrix.
-    pub data: Vec<Vec<K>>,
+    /// The data of the Matrix, stored row after row.
+    pub(crate) data: Vec<K>,
+    /// The number of rows of the Matrix.
+    pub(crate) rows: usize,
+    /// The number of columns of the Matrix.
+    pub(crate) cols: usize,
 }
 
 impl<K: Clone, const N: usize, const M: usize> From<[[K; N]; M]> for Matrix<K> {
@@ -19,12 +27,14 @@ impl<K: Clone, const N: usize, const M: usize> From<[[K; N]; M]> for Matrix<K> {
     /// A Matrix containing the elements of the 2D array.
     fn from(value: [[K; N]; M]) -> Self {
         Matrix {
-            data: value.map(|arr| arr.to_vec()).to_vec(),
+            data: value.iter().flat_map(|row| row.iter().cloned()).collect(),
+            rows: M,
+            cols: N,
         }
     }
 }
 
-impl<K: Clone> From<Vec<Vec<K>>> for Matrix<K> {
+impl<K> From<Vec<Vec<K>>> for Matrix<K> {
     /// Converts a 2D vector into a Matrix.
     ///
     /// # Arguments
@@ -34,19 +44,147 @@ impl<K: Clone> From<Vec<Vec<K>>> for Matrix<K> {
     /// # Returns
     ///
     /// A Matrix containing the elements of the 2D vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
     fn from(value: Vec<Vec<K>>) -> Self {
-        Matrix { data: value }
+        let [cols, rows] = check_rows(&value).unwrap_or_else(|e| panic!("{}", e));
+        Matrix {
+            data: value.into_iter().flatten().collect(),
+            rows,
+            cols,
+        }
     }
 }
 
+impl<K: Clone> TryFrom<&[Vec<K>]> for Matrix<K> {
+    type Error = MatrixError;
+
+    /// Converts a slice of rows into a Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The rows of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Matrix containing the elements of the rows, or a `MatrixError::ShapeMismatch`
+    /// if the rows do not all have the same length.
+    fn try_from(value: &[Vec<K>]) -> Result<Self, MatrixError> {
+        let [cols, rows] = check_rows(value)?;
+        Ok(Matrix {
+            data: value.iter().flatten().cloned().collect(),
+            rows,
+            cols,
+        })
+    }
+}
+
+/// Checks that rows all have the same length.
+///
+/// # Arguments
+///
+/// * `rows` - The rows of a Matrix.
+///
+/// # Returns
+///
+/// The shape of the Matrix, or a `MatrixError::ShapeMismatch` if a row does not have the
+/// length of the first one.
+fn check_rows<K>(rows: &[Vec<K>]) -> Result<[usize; 2], MatrixError> {
+    let cols = rows.first().map_or(0, |row| row.len());
+    if let Some(row) = rows.iter().find(|row| row.len() != cols) {
+        return Err(MatrixError::ShapeMismatch {
+            expected: [cols, 1],
+            found: [row.len(), 1],
+        });
+    }
+    Ok([cols, rows.len()])
+}
+
 impl<K> Matrix<K> {
+    /// Creates a Matrix from a row-major buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The elements of the Matrix, stored row after row.
+    /// * `rows` - The number of rows.
+    /// * `cols` - The number of columns.
+    ///
+    /// # Returns
+    ///
+    /// The new Matrix, or a `MatrixError::ShapeMismatch` if `data` does not hold exactly
+    /// `rows * cols` elements.
+    pub fn new(data: Vec<K>, rows: usize, cols: usize) -> Result<Self, MatrixError> {
+        if data.len() != rows * cols {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [cols, rows],
+                found: [data.len(), 1],
+            });
+        }
+        Ok(Matrix { data, rows, cols })
+    }
+
+    /// Returns the row-major index of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row of the element.
+    /// * `j` - The column of the element.
+    ///
+    /// # Returns
+    ///
+    /// The position of the element in `data`.
+    pub(crate) fn idx(&self, i: usize, j: usize) -> usize {
+        i * self.cols + j
+    }
+
+    /// Swaps two rows of the Matrix in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first row.
+    /// * `b` - The second row.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        let (head, tail) = self.data.split_at_mut(high * self.cols);
+        head[low * self.cols..(low + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+    }
+
+    /// Swaps two columns of the Matrix in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first column.
+    /// * `b` - The second column.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for row in self.data.chunks_exact_mut(self.cols) {
+            row.swap(a, b);
+        }
+    }
+
     /// Returns the shape of the Matrix as a 2-element array.
     ///
     /// # Returns
     ///
     /// An array containing the number of columns and rows in the Matrix.
     pub fn shape(&self) -> [usize; 2] {
-        [self.data[0].len(), self.data.len()]
+        [self.cols, self.rows]
+    }
+
+    /// Checks if the Matrix has as many rows as columns.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the Matrix is square, `false` otherwise.
+    pub fn is_square(&self) -> bool {
+        self.shape()[0] == self.shape()[1]
     }
 
     /// Checks if another Matrix has the same shape as this one.
@@ -64,6 +202,297 @@ impl<K> Matrix<K> {
             .zip(m.shape().iter())
             .all(|(a, b)| a == b)
     }
+
+    /// Iterates over the elements of the Matrix in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self.data.iter()
+    }
+
+    /// Iterates mutably over the elements of the Matrix in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, K> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over the rows of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the rows, each one as a contiguous slice.
+    pub fn rows(&self) -> impl Iterator<Item = &[K]> {
+        (0..self.rows).map(move |i| &self.data[i * self.cols..(i + 1) * self.cols])
+    }
+
+    /// Iterates over the columns of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the columns, each one as a single column view.
+    pub fn cols(&self) -> impl Iterator<Item = MatrixView<'_, K>> {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+
+    /// Checks that a position lies inside the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row of the position.
+    /// * `j` - The column of the position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn check_bounds(&self, i: usize, j: usize) {
+        if i >= self.rows || j >= self.cols {
+            panic!(
+                "Index ({}, {}) is out of bounds for a {}x{} matrix",
+                i, j, self.rows, self.cols
+            );
+        }
+    }
+}
+
+impl<K> std::ops::Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    /// Returns a reference to an element of the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `(i, j)` - The row and column of the element.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        self.check_bounds(i, j);
+        &self.data[self.idx(i, j)]
+    }
+}
+
+impl<K> std::ops::IndexMut<(usize, usize)> for Matrix<K> {
+    /// Returns a mutable reference to an element of the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `(i, j)` - The row and column of the element.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        self.check_bounds(i, j);
+        let index = self.idx(i, j);
+        &mut self.data[index]
+    }
+}
+
+impl<K> IntoIterator for Matrix<K> {
+    type Item = K;
+    type IntoIter = std::vec::IntoIter<K>;
+
+    /// Consumes the Matrix into an iterator over its elements in row-major order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a Matrix<K> {
+    type Item = &'a K;
+    type IntoIter = std::slice::Iter<'a, K>;
+
+    /// Iterates over references to the elements of the Matrix in row-major order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a mut Matrix<K> {
+    type Item = &'a mut K;
+    type IntoIter = std::slice::IterMut<'a, K>;
+
+    /// Iterates over mutable references to the elements of the Matrix in row-major order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+impl<K> FromIterator<Vector<K>> for Matrix<K> {
+    /// Collects rows into a Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The rows of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Matrix whose rows are the collected Vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same size.
+    fn from_iter<I: IntoIterator<Item = Vector<K>>>(iter: I) -> Self {
+        let mut res = Matrix {
+            data: vec![],
+            rows: 0,
+            cols: 0,
+        };
+        for row in iter {
+            if res.rows == 0 {
+                res.cols = row.size();
+            } else if row.size() != res.cols {
+                panic!(
+                    "{}",
+                    MatrixError::ShapeMismatch {
+                        expected: [res.cols, 1],
+                        found: [row.size(), 1],
+                    }
+                );
+            }
+            res.data.extend(row);
+            res.rows += 1;
+        }
+        res
+    }
+}
+
+impl<K: Clone + Default> Matrix<K> {
+    /// Creates a Matrix filled with zeroes.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows.
+    /// * `cols` - The number of columns.
+    ///
+    /// # Returns
+    ///
+    /// A `rows` by `cols` Matrix whose elements are all `K::default()`.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            data: vec![K::default(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Creates a square Matrix with the given elements on its diagonal.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagonal` - The elements of the diagonal.
+    ///
+    /// # Returns
+    ///
+    /// A square Matrix of the size of `diagonal`, zero outside of the diagonal.
+    pub fn from_diagonal(diagonal: &Vector<K>) -> Self {
+        let mut res = Matrix::zeros(diagonal.size(), diagonal.size());
+        for (i, value) in diagonal.iter().enumerate() {
+            res[(i, i)] = value.clone();
+        }
+        res
+    }
+
+    /// Creates the identity Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of rows and columns.
+    ///
+    /// # Returns
+    ///
+    /// A square Matrix with ones on its diagonal and zeroes elsewhere.
+    pub fn identity(n: usize) -> Self
+    where
+        K: Scalar,
+    {
+        Matrix::from_diagonal(&Vector::from(vec![K::one(); n]))
+    }
+}
+
+impl<K: Clone> Matrix<K> {
+    /// Creates a Matrix whose rows are the given Vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// The new Matrix, or a `MatrixError::ShapeMismatch` if the rows do not all have the
+    /// same size.
+    pub fn from_rows(rows: &[Vector<K>]) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, |row| row.size());
+        if let Some(row) = rows.iter().find(|row| row.size() != cols) {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [cols, 1],
+                found: [row.size(), 1],
+            });
+        }
+        Ok(Matrix {
+            data: rows.iter().flat_map(|row| row.iter().cloned()).collect(),
+            rows: rows.len(),
+            cols,
+        })
+    }
+
+    /// Creates a Matrix whose columns are the given Vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `cols` - The columns of the Matrix.
+    ///
+    /// # Returns
+    ///
+    /// The new Matrix, or a `MatrixError::ShapeMismatch` if the columns do not all have
+    /// the same size.
+    pub fn from_cols(cols: &[Vector<K>]) -> Result<Self, MatrixError> {
+        let rows = cols.first().map_or(0, |col| col.size());
+        if let Some(col) = cols.iter().find(|col| col.size() != rows) {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, rows],
+                found: col.shape(),
+            });
+        }
+        Ok(Matrix::from_fn(rows, cols.len(), |i, j| cols[j][i].clone()))
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Creates a Matrix by evaluating a function at every position.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows.
+    /// * `cols` - The number of columns.
+    /// * `f` - A function from the row and column of an element to its value.
+    ///
+    /// # Returns
+    ///
+    /// A `rows` by `cols` Matrix whose element at `(i, j)` is `f(i, j)`.
+    pub fn from_fn<F: FnMut(usize, usize) -> K>(rows: usize, cols: usize, mut f: F) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Matrix { data, rows, cols }
+    }
 }
 
 impl<K: Equals> PartialEq for Matrix<K> {
@@ -75,12 +504,15 @@ impl<K: Equals> PartialEq for Matrix<K> {
     ///
     /// # Returns
     ///
-    /// `true` if all elements are equal, `false` otherwise.
+    /// `true` if the Matrices have the same shape and all elements are equal, `false`
+    /// otherwise.
     fn eq(&self, v: &Self) -> bool {
-        self.data
-            .iter()
-            .zip(v.data.iter())
-            .all(|(a, b)| a.iter().zip(b.iter()).all(|(ax, by)| ax.equals(by)))
+        self.is_same_shape(v)
+            && self
+                .data
+                .iter()
+                .zip(v.data.iter())
+                .all(|(a, b)| a.equals(b))
     }
 }
 
@@ -96,9 +528,10 @@ impl<K: std::fmt::Debug> std::fmt::Display for Matrix<K> {
     /// A formatted string representation of the Matrix.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut res = String::from("");
-        for i in &self.data {
+        for i in 0..self.rows {
             res.push_str("[ ");
-            for (count, j) in i.iter().enumerate() {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            for (count, j) in row.iter().enumerate() {
                 if count > 0 {
                     res.push_str(", ");
                 }
@@ -125,18 +558,99 @@ impl<K: Copy + Default + num_traits::ops::mul_add::MulAdd<f32, K, Output = K>>
     /// # Returns
     ///
     /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two Matrices do not have the same shape.
     fn mul_add(self, a: f32, b: Self) -> Self::Output {
-        let mut res = Matrix::from(vec![vec![K::default(); self.shape()[0]]; self.shape()[1]]);
-        for i in 0..self.shape()[0] {
-            for j in 0..self.shape()[1] {
-                res.data[i][j] = self.data[i][j].mul_add(a, b.data[i][j]);
-            }
+        crate::ex02::TryMulAdd::try_mul_add(self, a, b).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<K: Scalar> std::ops::Add for Matrix<K> {
+    /// Adds two Matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to add.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn add(self, rhs: Self) -> Self::Output {
+        self._add(&rhs)
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar> std::ops::Add for &Matrix<K> {
+    /// Adds two Matrices without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to add.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn add(self, rhs: Self) -> Self::Output {
+        self._add(rhs)
+    }
+
+    type Output = Matrix<K>;
+}
+
+impl<K: Scalar> std::ops::AddAssign<&Matrix<K>> for Matrix<K> {
+    /// Adds another Matrix to this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to add.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn add_assign(&mut self, rhs: &Matrix<K>) {
+        if !self.is_same_shape(rhs) {
+            panic!(
+                "{}",
+                MatrixError::ShapeMismatch {
+                    expected: self.shape(),
+                    found: rhs.shape(),
+                }
+            );
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a += b.clone();
         }
-        res
     }
 }
 
-impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Matrix<K> {
+impl<K: Scalar> std::ops::AddAssign for Matrix<K> {
+    /// Adds another Matrix to this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to add.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn add_assign(&mut self, rhs: Matrix<K>) {
+        *self += &rhs;
+    }
+}
+
+impl<K: Scalar> std::ops::Sub for Matrix<K> {
     /// Subtracts two Matrices.
     ///
     /// # Arguments
@@ -146,6 +660,10 @@ impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Matrix<K>
     /// # Returns
     ///
     /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
     fn sub(self, rhs: Self) -> Self::Output {
         self._sub(&rhs)
     }
@@ -153,7 +671,98 @@ impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Matrix<K>
     type Output = Self;
 }
 
-impl<K: Copy + Default + std::ops::Mul<F, Output = K>, F: Copy> std::ops::Mul<F> for Matrix<K> {
+impl<K: Scalar> std::ops::Sub for &Matrix<K> {
+    /// Subtracts two Matrices without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self._sub(rhs)
+    }
+
+    type Output = Matrix<K>;
+}
+
+impl<K: Scalar> std::ops::SubAssign<&Matrix<K>> for Matrix<K> {
+    /// Subtracts another Matrix from this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to subtract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn sub_assign(&mut self, rhs: &Matrix<K>) {
+        if !self.is_same_shape(rhs) {
+            panic!(
+                "{}",
+                MatrixError::ShapeMismatch {
+                    expected: self.shape(),
+                    found: rhs.shape(),
+                }
+            );
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a -= b.clone();
+        }
+    }
+}
+
+impl<K: Scalar> std::ops::SubAssign for Matrix<K> {
+    /// Subtracts another Matrix from this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to subtract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same shape.
+    fn sub_assign(&mut self, rhs: Matrix<K>) {
+        *self -= &rhs;
+    }
+}
+
+impl<K: Scalar> std::ops::Neg for Matrix<K> {
+    /// Negates every element of a Matrix.
+    ///
+    /// # Returns
+    ///
+    /// The Matrix with the opposite elements.
+    fn neg(mut self) -> Self::Output {
+        for a in self.data.iter_mut() {
+            *a = -a.clone();
+        }
+        self
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar> std::ops::Neg for &Matrix<K> {
+    /// Negates every element of a Matrix without consuming it.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the opposite elements.
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+
+    type Output = Matrix<K>;
+}
+
+impl<K: Scalar> std::ops::Mul<K> for Matrix<K> {
     /// Multiplies a Matrix by a scalar.
     ///
     /// # Arguments
@@ -163,13 +772,250 @@ impl<K: Copy + Default + std::ops::Mul<F, Output = K>, F: Copy> std::ops::Mul<F>
     /// # Returns
     ///
     /// A new Matrix with the result of the operation.
-    fn mul(self, rhs: F) -> Self::Output {
+    fn mul(self, rhs: K) -> Self::Output {
+        self._scl(rhs)
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar> std::ops::Mul<K> for &Matrix<K> {
+    /// Multiplies a Matrix by a scalar without consuming it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The scalar to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    fn mul(self, rhs: K) -> Self::Output {
         self._scl(rhs)
     }
 
+    type Output = Matrix<K>;
+}
+
+impl<K: Scalar> std::ops::MulAssign<K> for Matrix<K> {
+    /// Multiplies a Matrix by a scalar in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The scalar to multiply by.
+    fn mul_assign(&mut self, rhs: K) {
+        for a in self.data.iter_mut() {
+            *a *= rhs.clone();
+        }
+    }
+}
+
+/// Implements the multiplication of a Matrix on the left by each of the given scalar types,
+/// which the orphan rule does not allow generically.
+macro_rules! impl_scalar_mul {
+    ($($scalar:ty),*) => {$(
+        impl std::ops::Mul<Matrix<$scalar>> for $scalar {
+            /// Multiplies a scalar by a Matrix.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The Matrix to multiply.
+            ///
+            /// # Returns
+            ///
+            /// A new Matrix with the result of the operation.
+            fn mul(self, rhs: Matrix<$scalar>) -> Self::Output {
+                rhs._scl(self)
+            }
+
+            type Output = Matrix<$scalar>;
+        }
+
+        impl std::ops::Mul<&Matrix<$scalar>> for $scalar {
+            /// Multiplies a scalar by a Matrix without consuming it.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The Matrix to multiply.
+            ///
+            /// # Returns
+            ///
+            /// A new Matrix with the result of the operation.
+            fn mul(self, rhs: &Matrix<$scalar>) -> Self::Output {
+                rhs._scl(self)
+            }
+
+            type Output = Matrix<$scalar>;
+        }
+    )*};
+}
+
+impl_scalar_mul!(
+    f32,
+    f64,
+    i32,
+    i64,
+    i128,
+    Complex<f32>,
+    Complex<f64>,
+    Ratio<i64>,
+    Ratio<BigInt>
+);
+
+/// Implements the multiplication of a Matrix of complex numbers by a real scalar of the same
+/// precision.
+macro_rules! impl_real_mul {
+    ($($complex:ty => $real:ty),*) => {$(
+        impl std::ops::Mul<$real> for Matrix<$complex> {
+            /// Multiplies a Matrix by a real scalar.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// A new Matrix with the result of the operation.
+            fn mul(self, rhs: $real) -> Self::Output {
+                self * <$complex>::from(rhs)
+            }
+
+            type Output = Self;
+        }
+
+        impl std::ops::Mul<$real> for &Matrix<$complex> {
+            /// Multiplies a Matrix by a real scalar without consuming it.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// A new Matrix with the result of the operation.
+            fn mul(self, rhs: $real) -> Self::Output {
+                self * <$complex>::from(rhs)
+            }
+
+            type Output = Matrix<$complex>;
+        }
+
+        impl std::ops::MulAssign<$real> for Matrix<$complex> {
+            /// Multiplies a Matrix by a real scalar in place.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            fn mul_assign(&mut self, rhs: $real) {
+                *self *= <$complex>::from(rhs);
+            }
+        }
+    )*};
+}
+
+impl_real_mul!(Complex<f32> => f32, Complex<f64> => f64);
+
+impl<K: Scalar> std::ops::Mul<Vector<K>> for Matrix<K> {
+    /// Multiplies a Matrix by a Vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the Vector differs from the number of columns of the Matrix.
+    fn mul(self, rhs: Vector<K>) -> Self::Output {
+        self.mul_vec(&rhs)
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::Mul<&Vector<K>> for &Matrix<K> {
+    /// Multiplies a Matrix by a Vector without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the Vector differs from the number of columns of the Matrix.
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        self.mul_vec(rhs)
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::Mul for Matrix<K> {
+    /// Multiplies two Matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `rhs` differs from the number of columns of the
+    /// Matrix.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat(&rhs)
+    }
+
     type Output = Self;
 }
 
+impl<K: Scalar> std::ops::Mul for &Matrix<K> {
+    /// Multiplies two Matrices without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Matrix with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `rhs` differs from the number of columns of the
+    /// Matrix.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat(rhs)
+    }
+
+    type Output = Matrix<K>;
+}
+
+impl<K: Scalar> std::ops::MulAssign<&Matrix<K>> for Matrix<K> {
+    /// Multiplies the Matrix on the right by another Matrix in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Matrix to multiply by.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `rhs` differs from the number of columns of the
+    /// Matrix.
+    fn mul_assign(&mut self, rhs: &Matrix<K>) {
+        *self = self.mul_mat(rhs);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,4 +1027,173 @@ mod tests {
         assert_eq!(m1.shape(), [3, 2]);
         assert_eq!(m2.shape(), [1, 1]);
     }
+
+    #[test]
+    fn test_eq() {
+        let m = Matrix::from([[1., 2.], [3., 4.]]);
+        assert_eq!(m, Matrix::from([[1., 2.], [3., 4.]]));
+        assert_ne!(m, Matrix::from([[1., 2.], [3., 5.]]));
+        assert_ne!(m, Matrix::from([[1., 2., 3., 4.]]));
+    }
+
+    #[test]
+    fn test_flat_storage() {
+        let m = Matrix::from(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(m.data, vec![1., 2., 3., 4., 5., 6.]);
+        assert_eq!(m.data[m.idx(1, 0)], 4.);
+        assert_eq!(
+            Matrix::new(vec![1., 2., 3., 4., 5., 6.], 2, 3)
+                .unwrap()
+                .shape(),
+            [3, 2]
+        );
+        assert_eq!(
+            Matrix::new(vec![1., 2., 3.], 2, 2).unwrap_err(),
+            MatrixError::ShapeMismatch {
+                expected: [2, 2],
+                found: [3, 1]
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Shape mismatch: expected 1x2, found 1x1")]
+    fn test_from_ragged() {
+        let _ = Matrix::from(vec![vec![1., 2.], vec![3.]]);
+    }
+
+    #[test]
+    fn test_try_from_rows() {
+        let rows = vec![vec![1., 2.], vec![3., 4.]];
+        assert_eq!(
+            Matrix::try_from(rows.as_slice()),
+            Ok(Matrix::from([[1., 2.], [3., 4.]]))
+        );
+        let ragged = vec![vec![1., 2.], vec![3.]];
+        assert_eq!(
+            Matrix::try_from(ragged.as_slice()),
+            Err(MatrixError::ShapeMismatch {
+                expected: [2, 1],
+                found: [1, 1]
+            })
+        );
+        let empty: Vec<Vec<f64>> = vec![];
+        assert_eq!(Matrix::from(empty).shape(), [0, 0]);
+    }
+
+    #[test]
+    fn test_index_and_iterators() {
+        let mut m = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(m[(1, 0)], 4.);
+        m[(0, 2)] = -3.;
+        for x in m.iter_mut() {
+            *x += 1.;
+        }
+        assert_eq!(m.iter().copied().sum::<f64>(), 21.);
+        assert_eq!(m.rows().nth(1), Some(&[5., 6., 7.][..]));
+        let cols: Vec<Vector<f64>> = m.cols().map(|c| c.to_vector()).collect();
+        assert_eq!(cols[2].data, vec![-2., 7.]);
+        let t: Matrix<f64> = cols.into_iter().collect();
+        assert_eq!(t, Matrix::from([[2., 5.], [3., 6.], [-2., 7.]]));
+        assert_eq!((&t).into_iter().count(), 6);
+        assert_eq!(t.into_iter().last(), Some(7.));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_out_of_bounds() {
+        let m = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let _ = m[(0, 3)];
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Matrix::from([[1., 2.], [3., 4.]]);
+        let b = Matrix::from([[7., 4.], [-2., 2.]]);
+        assert_eq!(&a + &b, Matrix::from([[8., 6.], [1., 6.]]));
+        assert_eq!(&a - &b, Matrix::from([[-6., -2.], [5., 2.]]));
+        assert_eq!(-&a, Matrix::from([[-1., -2.], [-3., -4.]]));
+        assert_eq!(&a * 2., Matrix::from([[2., 4.], [6., 8.]]));
+        assert_eq!(&a * &b, Matrix::from([[3., 8.], [13., 20.]]));
+        assert_eq!(&a * &Vector::from([1., 1.]), Vector::from([3., 7.]));
+        assert_eq!(a.clone() * b.clone(), &a * &b);
+        let mut c = a.clone();
+        c += &b;
+        c -= b.clone();
+        c *= 3.;
+        assert_eq!(c, Matrix::from([[3., 6.], [9., 12.]]));
+        c *= &Matrix::from([[0., 1.], [1., 0.]]);
+        assert_eq!(c, Matrix::from([[6., 3.], [12., 9.]]));
+        assert_eq!(a + b, Matrix::from([[8., 6.], [1., 6.]]));
+        let n = Matrix::from([[1i64, -2], [3, 4]]);
+        assert_eq!((&n * 2).data, vec![2, -4, 6, 8]);
+        assert_eq!((2 * &n).data, (&n * 2).data);
+        assert_eq!(0.5 * Matrix::from([[2., 4.]]), Matrix::from([[1., 2.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Shape mismatch")]
+    fn test_add_assign_shape_mismatch() {
+        let mut a = Matrix::from([[1., 2.], [3., 4.]]);
+        a += Matrix::from([[1., 2.]]);
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(
+            Matrix::<f64>::zeros(2, 3),
+            Matrix::from([[0., 0., 0.], [0., 0., 0.]])
+        );
+        assert_eq!(
+            Matrix::<f64>::identity(2),
+            Matrix::from([[1., 0.], [0., 1.]])
+        );
+        assert_eq!(
+            Matrix::from_fn(2, 2, |i, j| (i * 2 + j) as f64),
+            Matrix::from([[0., 1.], [2., 3.]])
+        );
+        assert_eq!(
+            Matrix::from_diagonal(&Vector::from([2., 3.])),
+            Matrix::from([[2., 0.], [0., 3.]])
+        );
+        let rows = [Vector::from([1., 2.]), Vector::from([3., 4.])];
+        assert_eq!(
+            Matrix::from_rows(&rows),
+            Ok(Matrix::from([[1., 2.], [3., 4.]]))
+        );
+        assert_eq!(
+            Matrix::from_cols(&rows),
+            Ok(Matrix::from([[1., 3.], [2., 4.]]))
+        );
+        let ragged = [Vector::from([1., 2.]), Vector::from([3.])];
+        assert_eq!(
+            Matrix::from_rows(&ragged),
+            Err(MatrixError::ShapeMismatch {
+                expected: [2, 1],
+                found: [1, 1]
+            })
+        );
+        assert_eq!(
+            Matrix::from_cols(&ragged),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 1]
+            })
+        );
+        assert_eq!(Matrix::<f64>::from_rows(&[]).map(|m| m.shape()), Ok([0, 0]));
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut m = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        m.swap_rows(2, 0);
+        assert_eq!(m.data, vec![5., 6., 3., 4., 1., 2.]);
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut m = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        m.swap_cols(2, 0);
+        assert_eq!(m.data, vec![3., 2., 1., 6., 5., 4.]);
+    }
 }
@@ -0,0 +1,314 @@
+use crate::{
+    ex09::Conjugate,
+    ex10::FirstNonZero,
+    scalar::{Field, Scalar},
+    Equals,
+};
+
+/// An element of the prime field GF(P), stored as its least non-negative residue.
+///
+/// `P` must be a prime number for division to be well defined. This is checked at compile
+/// time: constructing an element of `Modular<P>` for a `P` that is not prime fails to build.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modular<const P: u64>(u64);
+
+impl<const P: u64> Modular<P> {
+    /// Evaluates to `()` when `P` is prime, and fails const evaluation otherwise.
+    const PRIME: () = assert!(is_prime(P), "the modulus of Modular<P> must be prime");
+
+    /// Creates an element of GF(P).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Any integer, reduced modulo `P`.
+    ///
+    /// # Returns
+    ///
+    /// The residue class of `value`.
+    pub fn new(value: u64) -> Self {
+        let () = Self::PRIME;
+        Modular(value % P)
+    }
+
+    /// Returns the least non-negative residue of the element.
+    ///
+    /// # Returns
+    ///
+    /// An integer between `0` and `P - 1`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Raises the element to an integer power by repeated squaring.
+    ///
+    /// # Arguments
+    ///
+    /// * `exp` - The exponent.
+    ///
+    /// # Returns
+    ///
+    /// The element to the power of `exp`.
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut res = Modular::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        res
+    }
+}
+
+impl<const P: u64> From<i64> for Modular<P> {
+    /// Converts a signed integer into its residue class.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A signed integer.
+    ///
+    /// # Returns
+    ///
+    /// The residue class of `value`.
+    fn from(value: i64) -> Self {
+        let () = Self::PRIME;
+        Modular((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> std::fmt::Display for Modular<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> std::ops::Add for Modular<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Modular(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> std::ops::Sub for Modular<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> std::ops::Mul for Modular<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Modular(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> std::ops::Div for Modular<P> {
+    type Output = Self;
+
+    /// Divides by multiplying with the inverse given by Fermat's little theorem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            panic!("Division by zero in GF({})", P);
+        }
+        self * rhs.pow(P - 2)
+    }
+}
+
+impl<const P: u64> std::ops::Neg for Modular<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Modular((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> std::ops::AddAssign for Modular<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> std::ops::SubAssign for Modular<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> std::ops::MulAssign for Modular<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> std::ops::DivAssign for Modular<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Scalar for Modular<P> {
+    fn zero() -> Self {
+        Modular::new(0)
+    }
+
+    fn one() -> Self {
+        Modular::new(1)
+    }
+}
+
+impl<const P: u64> Field for Modular<P> {
+    type Pivot = FirstNonZero;
+}
+
+impl<const P: u64> Equals for Modular<P> {
+    fn equals(&self, v: &Self) -> bool {
+        self == v
+    }
+}
+
+impl<const P: u64> Conjugate for Modular<P> {
+    fn conjugate(&self) -> Modular<P> {
+        *self
+    }
+}
+
+/// Computes `a * b mod m` without overflowing.
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Checks whether `n` is prime with a Miller-Rabin test, using bases that make it
+/// deterministic for every `u64`.
+///
+/// # Arguments
+///
+/// * `n` - The number to test.
+///
+/// # Returns
+///
+/// `true` if `n` is prime, `false` otherwise.
+const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+    let mut d = n - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let (mut x, mut base, mut exp) = (1, BASES[i], d);
+        i += 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                x = mul_mod(x, base, n);
+            }
+            base = mul_mod(base, base, n);
+            exp >>= 1;
+        }
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::MatrixError, matrix::Matrix, vector::Vector};
+
+    type F7 = Modular<7>;
+
+    fn f7<const N: usize, const M: usize>(rows: [[i64; N]; M]) -> Matrix<F7> {
+        Matrix::from(rows.map(|row| row.map(F7::from)))
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u64> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+        assert!(is_prime(18446744073709551557));
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(-F7::new(0), F7::new(0));
+        assert_eq!(F7::from(-1), F7::new(6));
+        assert_eq!(F7::new(3).pow(6), F7::one());
+        let p = Modular::<18446744073709551557>::new(u64::MAX - 100);
+        assert_eq!(p * p.inv(), Modular::one());
+        assert_eq!(F7::new(4).to_string(), "4 (mod 7)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero in GF(7)")]
+    fn test_division_by_zero() {
+        let _ = F7::new(1) / F7::new(7);
+    }
+
+    #[test]
+    fn test_linear_algebra() {
+        let u = f7([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+        assert_eq!(u.determinant(), F7::from(-3));
+        assert_eq!(u.rank(), 3);
+        assert_eq!(&u * &u.inverse(), Matrix::identity(3));
+        let u = f7([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(u.rank(), 2);
+        assert_eq!(u.try_inverse(), Err(MatrixError::Singular));
+        assert_eq!(u.row_echelon(), f7([[1, 0, -1], [0, 1, 2], [0, 0, 0]]));
+        let u = f7([[1, 1], [1, 8]]);
+        assert_eq!(u.rank(), 1);
+        assert_eq!(u.determinant(), F7::zero());
+    }
+
+    #[test]
+    fn test_solve() {
+        let u = Matrix::from([[1, 1, 0], [0, 1, 1], [1, 0, 1]].map(|r| r.map(Modular::<2>::from)));
+        let b = Vector::from([1, 0, 1].map(Modular::<2>::from));
+        assert_eq!(u.try_solve_unique(&b), Err(MatrixError::Singular));
+        let u = f7([[2, 1], [1, 3]]);
+        let b = Vector::from([F7::new(1), F7::new(2)]);
+        let x = u.solve_unique(&b);
+        assert_eq!(&u * &x, b);
+    }
+}
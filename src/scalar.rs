@@ -0,0 +1,402 @@
+use num::{bigint::BigInt, rational::Ratio};
+
+use crate::{
+    ex04::Modulus,
+    ex09::Conjugate,
+    ex10::{FirstNonZero, PartialPivoting, PivotStrategy},
+    Complex,
+};
+
+/// The elements of a commutative ring, usable as the entries of Vectors and Matrices.
+///
+/// Only `Clone` is required so that arbitrary-precision numbers can be used as well.
+pub trait Scalar:
+    Clone
+    + Default
+    + PartialEq
+    + std::fmt::Debug
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Neg<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::SubAssign
+    + std::ops::MulAssign
+{
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Checks if the element is the additive identity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the element is zero, `false` otherwise.
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Computes `self * a + b`, with a single rounding for the types that support it.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The factor.
+    /// * `b` - The term to add.
+    ///
+    /// # Returns
+    ///
+    /// The result of the operation.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
+/// The elements of a field, a Scalar in which every non-zero element can be inverted.
+pub trait Field: Scalar + std::ops::Div<Output = Self> + std::ops::DivAssign {
+    /// The pivot strategy used by default when eliminating.
+    type Pivot: PivotStrategy<Self>;
+
+    /// Computes the multiplicative inverse.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the element, which is meaningless if the element is zero.
+    fn inv(&self) -> Self {
+        Self::one() / self.clone()
+    }
+
+    /// Checks if the element counts as zero at a given tolerance.
+    ///
+    /// Exact fields ignore the tolerance, floating point ones compare it with the modulus.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the element is negligible, `false` otherwise.
+    fn is_negligible(&self, _tolerance: f64) -> bool {
+        self.is_zero()
+    }
+
+    /// Computes the tolerance under which eliminating a matrix treats entries as zero.
+    ///
+    /// Exact fields need none, floating point ones scale the machine epsilon by the
+    /// largest dimension of the matrix and the largest modulus of its entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The entries of the matrix.
+    /// * `size` - The largest dimension of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The largest modulus that counts as zero.
+    fn default_tolerance(_entries: &[Self], _size: usize) -> f64 {
+        0.
+    }
+}
+
+/// The real and complex floating point fields, with the conjugation and modulus that
+/// orthogonal factorizations are built on.
+pub trait ComplexField: Field + Conjugate + Modulus {
+    /// Embeds a real number in the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The real number.
+    ///
+    /// # Returns
+    ///
+    /// The element whose real part is `x` and imaginary part is zero.
+    fn from_real(x: Self::Real) -> Self;
+
+    /// Returns the real part of the element.
+    ///
+    /// # Returns
+    ///
+    /// The real part of the element.
+    fn real(&self) -> Self::Real;
+}
+
+/// The elements of an integral domain in which exact divisions can be carried out, with
+/// arithmetic that reports overflows instead of wrapping.
+pub trait IntegerScalar: Scalar {
+    /// Adds two elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The element to add.
+    ///
+    /// # Returns
+    ///
+    /// The sum, or `None` if it overflows.
+    fn checked_add(&self, v: &Self) -> Option<Self>;
+
+    /// Subtracts an element from another.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The element to subtract.
+    ///
+    /// # Returns
+    ///
+    /// The difference, or `None` if it overflows.
+    fn checked_sub(&self, v: &Self) -> Option<Self>;
+
+    /// Multiplies two elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The element to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// The product, or `None` if it overflows.
+    fn checked_mul(&self, v: &Self) -> Option<Self>;
+
+    /// Divides an element by one of its divisors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A non-zero divisor of the element.
+    ///
+    /// # Returns
+    ///
+    /// The quotient, or `None` if it overflows or `v` is zero.
+    fn checked_exact_div(&self, v: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_float_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0.
+            }
+
+            fn one() -> Self {
+                1.
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                <$t>::mul_add(self, a, b)
+            }
+        }
+
+        impl Field for $t {
+            type Pivot = PartialPivoting;
+
+            fn is_negligible(&self, tolerance: f64) -> bool {
+                f64::from(self.modulus()) <= tolerance
+            }
+
+            fn default_tolerance(entries: &[Self], size: usize) -> f64 {
+                let largest = entries
+                    .iter()
+                    .fold(0f64, |res, x| res.max(f64::from(x.modulus())));
+                largest * f64::from(<$t>::EPSILON) * size as f64
+            }
+        }
+
+        impl ComplexField for $t {
+            fn from_real(x: $t) -> Self {
+                x
+            }
+
+            fn real(&self) -> $t {
+                *self
+            }
+        }
+
+        impl Scalar for Complex<$t> {
+            fn zero() -> Self {
+                Complex(num::Complex::new(0., 0.))
+            }
+
+            fn one() -> Self {
+                Complex(num::Complex::new(1., 0.))
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                num_traits::MulAdd::mul_add(self, a, b)
+            }
+        }
+
+        impl Field for Complex<$t> {
+            type Pivot = PartialPivoting;
+
+            fn is_negligible(&self, tolerance: f64) -> bool {
+                f64::from(self.modulus()) <= tolerance
+            }
+
+            fn default_tolerance(entries: &[Self], size: usize) -> f64 {
+                let largest = entries
+                    .iter()
+                    .fold(0f64, |res, x| res.max(f64::from(x.modulus())));
+                largest * f64::from(<$t>::EPSILON) * size as f64
+            }
+        }
+
+        impl ComplexField for Complex<$t> {
+            fn from_real(x: $t) -> Self {
+                Complex(num::Complex::new(x, 0.))
+            }
+
+            fn real(&self) -> $t {
+                self.0.re
+            }
+        }
+    )*};
+}
+
+impl_float_scalar!(f32, f64);
+
+macro_rules! impl_ratio_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for Ratio<$t> {
+            fn zero() -> Self {
+                Ratio::from_integer(<$t>::from(0))
+            }
+
+            fn one() -> Self {
+                Ratio::from_integer(<$t>::from(1))
+            }
+        }
+
+        impl Field for Ratio<$t> {
+            type Pivot = FirstNonZero;
+        }
+    )*};
+}
+
+impl_ratio_scalar!(i64, BigInt);
+
+macro_rules! impl_integer_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+        }
+
+        impl IntegerScalar for $t {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *v)
+            }
+
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *v)
+            }
+
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *v)
+            }
+
+            fn checked_exact_div(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_div(*self, *v)
+            }
+        }
+    )*};
+}
+
+impl_integer_scalar!(i32, i64, i128);
+
+impl Scalar for BigInt {
+    fn zero() -> Self {
+        BigInt::from(0)
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+}
+
+impl IntegerScalar for BigInt {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self - v)
+    }
+
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+
+    fn checked_exact_div(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self / v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum_of_squares<K: Scalar>(values: &[K]) -> K {
+        values
+            .iter()
+            .fold(K::zero(), |acc, x| x.clone().mul_add(x.clone(), acc))
+    }
+
+    #[test]
+    fn test_scalar() {
+        assert_eq!(sum_of_squares(&[1f32, 2., 3.]), 14.);
+        assert_eq!(sum_of_squares(&[1f64, -2.]), 5.);
+        assert_eq!(
+            sum_of_squares(&[Complex(num::Complex::new(0f64, 1.))]),
+            Complex::from(-1.)
+        );
+        assert!(f64::zero().is_zero());
+        assert!(!Complex::<f32>::one().is_zero());
+    }
+
+    #[test]
+    fn test_ratio() {
+        let third = Ratio::new(1i64, 3);
+        assert_eq!(sum_of_squares(&[third, third, third]), third);
+        let big = Ratio::from_integer(BigInt::from(i64::MAX));
+        assert_eq!(
+            sum_of_squares(std::slice::from_ref(&big)) / big.clone(),
+            big
+        );
+        assert_eq!(
+            Ratio::new(BigInt::from(-2), BigInt::from(7)).inv(),
+            Ratio::new(BigInt::from(-7), BigInt::from(2))
+        );
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(sum_of_squares(&[3i64, 4]), 25);
+        assert_eq!(IntegerScalar::checked_add(&i64::MAX, &1), None);
+        assert_eq!(IntegerScalar::checked_exact_div(&12i32, &4), Some(3));
+        assert_eq!(
+            IntegerScalar::checked_exact_div(&BigInt::from(1), &BigInt::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!(4f64.inv(), 0.25);
+        let i = Complex(num::Complex::new(0f32, 1.));
+        assert_eq!(i.inv(), Complex(num::Complex::new(0f32, -1.)));
+        assert_eq!(Complex::<f64>::from_real(2.), Complex::from(2.));
+        assert_eq!(Complex(num::Complex::new(3f64, 4.)).real(), 3.);
+        assert_eq!((-2f32).real(), -2.);
+        assert!(1e-13f64.is_negligible(1e-12) && !1e-11f64.is_negligible(1e-12));
+        assert!(Complex(num::Complex::new(0f32, -1e-7)).is_negligible(1e-6));
+        assert!(!Ratio::new(1i64, 1_000_000_000).is_negligible(1e-6));
+        assert!(Ratio::new(0i64, 1).is_negligible(0.));
+        assert_eq!(f64::default_tolerance(&[1., -4., 2.], 2), 8. * f64::EPSILON);
+        assert_eq!(Ratio::default_tolerance(&[Ratio::new(1i64, 3)], 1), 0.);
+    }
+}
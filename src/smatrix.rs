@@ -0,0 +1,277 @@
+use crate::{
+    error::MatrixError, ex09::Conjugate, matrix::Matrix, scalar::Scalar, svector::SVector, Equals,
+};
+
+/// A generic Matrix struct whose shape is known at compile time and whose data lives on
+/// the stack.
+#[derive(Clone, Copy, Debug)]
+pub struct SMatrix<K, const R: usize, const C: usize> {
+    /// The data of the SMatrix, as `R` rows of `C` elements.
+    pub data: [[K; C]; R],
+}
+
+impl<K, const R: usize, const C: usize> From<[[K; C]; R]> for SMatrix<K, R, C> {
+    /// Converts a fixed-size 2D array into a SMatrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A 2D array of elements.
+    ///
+    /// # Returns
+    ///
+    /// A SMatrix containing the elements of the 2D array.
+    fn from(value: [[K; C]; R]) -> Self {
+        SMatrix { data: value }
+    }
+}
+
+impl<K: Clone, const R: usize, const C: usize> From<SMatrix<K, R, C>> for Matrix<K> {
+    /// Converts a SMatrix into a dynamically sized Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A SMatrix.
+    ///
+    /// # Returns
+    ///
+    /// A Matrix containing the elements of the SMatrix.
+    fn from(value: SMatrix<K, R, C>) -> Self {
+        Matrix::from(value.data)
+    }
+}
+
+impl<K: Clone, const R: usize, const C: usize> TryFrom<&Matrix<K>> for SMatrix<K, R, C> {
+    type Error = MatrixError;
+
+    /// Converts a dynamically sized Matrix into a SMatrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A Matrix.
+    ///
+    /// # Returns
+    ///
+    /// A SMatrix containing the elements of the Matrix, or a
+    /// `MatrixError::ShapeMismatch` if the Matrix is not `R` by `C`.
+    fn try_from(value: &Matrix<K>) -> Result<Self, Self::Error> {
+        if value.shape() != [C, R] {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [C, R],
+                found: value.shape(),
+            });
+        }
+        Ok(SMatrix {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| value.data[i * C + j].clone())),
+        })
+    }
+}
+
+impl<K: Equals, const R: usize, const C: usize> PartialEq for SMatrix<K, R, C> {
+    /// Checks if two SMatrices are equal by comparing their elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another SMatrix to compare with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all elements are equal, `false` otherwise.
+    fn eq(&self, v: &Self) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(v.data.iter().flatten())
+            .all(|(a, b)| a.equals(b))
+    }
+}
+
+impl<K, const R: usize, const C: usize> SMatrix<K, R, C> {
+    /// Returns the shape of the SMatrix as a 2-element array.
+    ///
+    /// # Returns
+    ///
+    /// An array containing the number of columns and rows in the SMatrix.
+    pub fn shape(&self) -> [usize; 2] {
+        [C, R]
+    }
+
+    /// Creates a SMatrix whose elements are computed from their position.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function mapping a row and a column index to an element.
+    ///
+    /// # Returns
+    ///
+    /// A SMatrix whose element at `(i, j)` is `f(i, j)`.
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> K) -> Self {
+        SMatrix {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))),
+        }
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> SMatrix<K, R, C> {
+    /// Adds two matrices element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - Another matrix of the same shape to add.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the element-wise sum of the two matrices.
+    pub fn _add(&self, m: &SMatrix<K, R, C>) -> Self {
+        SMatrix::from_fn(|i, j| self.data[i][j].clone() + m.data[i][j].clone())
+    }
+
+    /// Subtracts one matrix from another element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - Another matrix of the same shape to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the element-wise difference of the two matrices.
+    pub fn _sub(&self, m: &SMatrix<K, R, C>) -> Self {
+        SMatrix::from_fn(|i, j| self.data[i][j].clone() - m.data[i][j].clone())
+    }
+
+    /// Multiplies a matrix by a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The scalar to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix that is the original matrix scaled by the scalar.
+    pub fn _scl<F>(&self, a: F) -> Self
+    where
+        K: std::ops::Mul<F, Output = K>,
+        F: Clone,
+    {
+        SMatrix::from_fn(|i, j| self.data[i][j].clone() * a.clone())
+    }
+
+    /// Computes the conjugate transpose of a matrix.
+    ///
+    /// # Returns
+    ///
+    /// The conjugate transpose of the matrix.
+    pub fn transpose(&self) -> SMatrix<K, C, R>
+    where
+        K: Conjugate,
+    {
+        SMatrix::from_fn(|i, j| self.data[j][i].conjugate())
+    }
+
+    /// Multiplies a matrix by a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to multiply by, with as many elements as the matrix has
+    ///   columns.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with as many elements as the matrix has rows.
+    pub fn mul_vec(&self, vec: &SVector<K, C>) -> SVector<K, R> {
+        let data = std::array::from_fn(|i| {
+            self.data[i]
+                .iter()
+                .zip(vec.data.iter())
+                .fold(K::zero(), |res, (a, x)| x.clone().mul_add(a.clone(), res))
+        });
+        SVector::from(data)
+    }
+
+    /// Multiplies a matrix by another matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `mat` - The matrix to multiply by, with as many rows as this matrix has columns.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix with the rows of this matrix and the columns of `mat`.
+    pub fn mul_mat<const P: usize>(&self, mat: &SMatrix<K, C, P>) -> SMatrix<K, R, P> {
+        let mut res = SMatrix::from_fn(|_, _| K::zero());
+        for i in 0..R {
+            for k in 0..C {
+                for j in 0..P {
+                    res.data[i][j] = mat.data[k][j]
+                        .clone()
+                        .mul_add(self.data[i][k].clone(), res.data[i][j].clone());
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<K: Scalar, const N: usize> SMatrix<K, N, N> {
+    /// Computes the trace of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// The trace of the matrix.
+    pub fn trace(&self) -> K {
+        let mut res = K::zero();
+        for i in 0..N {
+            res += self.data[i][i].clone();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub_scl() {
+        let u = SMatrix::from([[1., 2.], [3., 4.]]);
+        let v = SMatrix::from([[7., 4.], [-2., 2.]]);
+        assert_eq!(u._add(&v), SMatrix::from([[8., 6.], [1., 6.]]));
+        assert_eq!(u._sub(&v), SMatrix::from([[-6., -2.], [5., 2.]]));
+        assert_eq!(u._scl(2.), SMatrix::from([[2., 4.], [6., 8.]]));
+    }
+
+    #[test]
+    fn test_mul_vec_mul_mat() {
+        let u = SMatrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(
+            u.mul_vec(&SVector::from([1., 0., 1.])),
+            SVector::from([4., 10.])
+        );
+        let v = SMatrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        let w: SMatrix<f64, 2, 2> = u.mul_mat(&v);
+        assert_eq!(w, SMatrix::from([[4., 5.], [10., 11.]]));
+    }
+
+    #[test]
+    fn test_transpose_trace() {
+        let u = SMatrix::from([[-2., -8., 4.], [1., -23., 4.]]);
+        let t: SMatrix<f64, 3, 2> = u.transpose();
+        assert_eq!(t, SMatrix::from([[-2., 1.], [-8., -23.], [4., 4.]]));
+        assert_eq!(SMatrix::from([[2., 1.], [0., 3.]]).trace(), 5.);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let u = SMatrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let m = Matrix::from(u);
+        assert_eq!(m.shape(), [3, 2]);
+        let v: SMatrix<f64, 2, 3> = SMatrix::try_from(&m).unwrap();
+        assert_eq!(v, u);
+        assert_eq!(
+            SMatrix::<f64, 3, 2>::try_from(&m),
+            Err(MatrixError::ShapeMismatch {
+                expected: [2, 3],
+                found: [3, 2]
+            })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    error::MatrixError, ex10::gauss_jordan, matrix::Matrix, scalar::Field, subspace::null_basis,
+    vector::Vector, Equals,
+};
+
+/// The solution set of a linear system `A * x = b`.
+#[derive(Clone, Debug)]
+pub enum Solution<K> {
+    /// The system has exactly one solution.
+    Unique(Vector<K>),
+    /// The system has infinitely many solutions, the sums of `particular` and of any
+    /// combination of the vectors of `null_basis`.
+    Infinite {
+        /// The solution whose free variables are all zero.
+        particular: Vector<K>,
+        /// A basis of the null space of `A`.
+        null_basis: Vec<Vector<K>>,
+    },
+    /// The system has no solution.
+    Inconsistent,
+}
+
+impl<K: Equals> PartialEq for Solution<K> {
+    /// Checks if two solution sets are described by the same vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other solution set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both are of the same kind with equal vectors, `false` otherwise.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Solution::Unique(a), Solution::Unique(b)) => a == b,
+            (
+                Solution::Infinite {
+                    particular: a,
+                    null_basis: a_basis,
+                },
+                Solution::Infinite {
+                    particular: b,
+                    null_basis: b_basis,
+                },
+            ) => a == b && a_basis == b_basis,
+            (Solution::Inconsistent, Solution::Inconsistent) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// Solves the linear system `self * x = b` for a matrix of any shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution set of the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `b` differs from the number of rows of the matrix.
+    pub fn solve(&self, b: &Vector<K>) -> Solution<K> {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Solves the linear system `self * x = b` for a matrix of any shape.
+    ///
+    /// Pivots are treated as zero below the default tolerance of the matrix, and the
+    /// system is inconsistent when an entry left of `b` by the elimination exceeds the
+    /// default tolerance of `b`, both of which are zero for exact fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    ///
+    /// # Returns
+    ///
+    /// The solution set of the system, or a `MatrixError::ShapeMismatch` if the size of
+    /// `b` differs from the number of rows of the matrix.
+    pub fn try_solve(&self, b: &Vector<K>) -> Result<Solution<K>, MatrixError> {
+        let size = self.rows.max(self.cols);
+        self.solve_augmented(
+            b,
+            self.default_tolerance(),
+            K::default_tolerance(&b.data, size),
+        )
+    }
+
+    /// Solves the linear system `self * x = b` for a matrix of any shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// The solution set of the system, or a `MatrixError::ShapeMismatch` if the size of
+    /// `b` differs from the number of rows of the matrix.
+    pub fn try_solve_with(
+        &self,
+        b: &Vector<K>,
+        tolerance: f64,
+    ) -> Result<Solution<K>, MatrixError> {
+        self.solve_augmented(b, tolerance, tolerance)
+    }
+
+    /// Solves the linear system `self * x = b` for a matrix of any shape.
+    ///
+    /// The augmented matrix `[self | b]` is brought to reduced row echelon form with
+    /// pivots in the columns of the matrix only: a non-zero entry left in the last column
+    /// below the pivot rows makes the system inconsistent, and otherwise every column of
+    /// the matrix without a pivot is a free variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side, with as many elements as the matrix has rows.
+    /// * `tolerance` - The largest modulus of a pivot that counts as zero.
+    /// * `residual_tolerance` - The largest modulus of an entry left in the last column
+    ///   that counts as zero.
+    ///
+    /// # Returns
+    ///
+    /// The solution set of the system, or a `MatrixError::ShapeMismatch` if the size of
+    /// `b` differs from the number of rows of the matrix.
+    fn solve_augmented(
+        &self,
+        b: &Vector<K>,
+        tolerance: f64,
+        residual_tolerance: f64,
+    ) -> Result<Solution<K>, MatrixError> {
+        let (m, n) = (self.rows, self.cols);
+        if b.size() != m {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, m],
+                found: b.shape(),
+            });
+        }
+        let mut rref = Matrix::from_fn(m, n + 1, |i, j| {
+            if j < n {
+                self[(i, j)].clone()
+            } else {
+                b[i].clone()
+            }
+        });
+        let pivots = gauss_jordan::<K, K::Pivot>(&mut rref, n, tolerance);
+        if (pivots.len()..m).any(|i| !rref[(i, n)].is_negligible(residual_tolerance)) {
+            return Ok(Solution::Inconsistent);
+        }
+        let mut particular = Vector::zeros(n);
+        for (i, &j) in pivots.iter().enumerate() {
+            particular[j] = rref[(i, n)].clone();
+        }
+        if pivots.len() == n {
+            return Ok(Solution::Unique(particular));
+        }
+        Ok(Solution::Infinite {
+            particular,
+            null_basis: null_basis(&rref, &pivots, n),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vector_approx_eq;
+    use num::rational::Ratio;
+
+    #[test]
+    fn test_solve_unique() {
+        let a = Matrix::from([[2., 1.], [1., 3.]]);
+        assert_eq!(
+            a.solve(&Vector::from([3., 5.])),
+            Solution::Unique(Vector::from([0.8, 1.4]))
+        );
+        let a = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        assert_eq!(
+            a.solve(&Vector::from([1., 2., 3.])),
+            Solution::Unique(Vector::from([1., 2.]))
+        );
+        let b = Vector::from([1e20, 1e20]);
+        assert_eq!(
+            Matrix::identity(2).solve(&b),
+            Solution::Unique(Vector::from([1e20, 1e20]))
+        );
+        let Solution::Unique(x) =
+            (&Matrix::<f64>::identity(2) * 1e-20).solve(&Vector::from([1., 1.]))
+        else {
+            panic!("expected a unique solution");
+        };
+        assert_vector_approx_eq!(x, Vector::from([1e20, 1e20]), rel = 1e-12);
+    }
+
+    #[test]
+    fn test_solve_infinite() {
+        let a = Matrix::from([[1., 2., 0., 1.], [2., 4., 1., 4.], [3., 6., 1., 5.]]);
+        let b = Vector::from([1., 3., 4.]);
+        let Solution::Infinite {
+            particular,
+            null_basis,
+        } = a.solve(&b)
+        else {
+            panic!("expected infinitely many solutions");
+        };
+        assert_eq!(particular, Vector::from([1., 0., 1., 0.]));
+        assert_eq!(&a * &particular, b);
+        assert_eq!(null_basis, a.null_space(a.default_tolerance()));
+        let a = Matrix::from([[1., 1.]]);
+        assert_eq!(
+            a.solve(&Vector::from([2.])),
+            Solution::Infinite {
+                particular: Vector::from([2., 0.]),
+                null_basis: vec![Vector::from([-1., 1.])],
+            }
+        );
+    }
+
+    #[test]
+    fn test_solve_inconsistent() {
+        let a = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(a.solve(&Vector::from([1., 3.])), Solution::Inconsistent);
+        let a = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        assert_eq!(a.solve(&Vector::from([1., 2., 4.])), Solution::Inconsistent);
+        let a = Matrix::from([[1., 1.], [1., 1. + 1e-15]]);
+        let b = Vector::from([1., 1. + 1e-3]);
+        assert!(matches!(a.try_solve_with(&b, 0.), Ok(Solution::Unique(_))));
+        assert_eq!(a.try_solve_with(&b, 1e-12), Ok(Solution::Inconsistent));
+        assert_eq!(
+            a.try_solve(&Vector::from([1.])),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 1]
+            })
+        );
+    }
+
+    #[test]
+    fn test_solve_rational() {
+        let r = Ratio::new;
+        let a = Matrix::from([
+            [r(1, 3), r(1, 7), r(1, 11)],
+            [r(1, 5), r(1, 9), r(1, 13)],
+            [r(2, 3), r(2, 7), r(2, 11)],
+        ]);
+        let b = Vector::from([r(1, 1), r(1, 1), r(2, 1)]);
+        let Solution::Infinite {
+            particular,
+            null_basis,
+        } = a.solve(&b)
+        else {
+            panic!("expected infinitely many solutions");
+        };
+        assert_eq!(&a * &particular, b);
+        assert_eq!(null_basis.len(), 1);
+        let b = Vector::from([r(1, 1), r(1, 1), r(1, 1)]);
+        assert_eq!(a.solve(&b), Solution::Inconsistent);
+    }
+}
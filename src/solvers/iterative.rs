@@ -0,0 +1,663 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{
+    decomposition::schur::givens,
+    error::MatrixError,
+    ex04::Modulus,
+    matrix::Matrix,
+    scalar::{ComplexField, Scalar},
+    vector::Vector,
+};
+
+/// A linear map that can be applied to vectors without storing its matrix.
+pub trait LinearOperator<K> {
+    /// Returns the shape of the operator.
+    ///
+    /// # Returns
+    ///
+    /// The number of columns and rows of the operator, in the order of `Matrix::shape`.
+    fn shape(&self) -> [usize; 2];
+
+    /// Applies the operator to a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The vector, with as many elements as the operator has columns.
+    ///
+    /// # Returns
+    ///
+    /// The image of the vector, with as many elements as the operator has rows.
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+}
+
+impl<K: Scalar> LinearOperator<K> for Matrix<K> {
+    fn shape(&self) -> [usize; 2] {
+        Matrix::shape(self)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+/// A linear operator whose diagonal is known, which is all the Jacobi method needs.
+pub trait DiagonalAccess<K>: LinearOperator<K> {
+    /// Returns the diagonal of the operator.
+    ///
+    /// # Returns
+    ///
+    /// The entries at the same row and column, as many as the smallest dimension.
+    fn diagonal(&self) -> Vector<K>;
+}
+
+impl<K: Scalar> DiagonalAccess<K> for Matrix<K> {
+    fn diagonal(&self) -> Vector<K> {
+        (0..self.rows.min(self.cols))
+            .map(|i| self[(i, i)].clone())
+            .collect()
+    }
+}
+
+/// The stopping criteria of an iterative solver.
+#[derive(Clone, Copy, Debug)]
+pub struct IterativeOptions<R> {
+    /// The tolerance on the residual norm `‖b - A * x‖` relative to `‖b‖`.
+    pub tolerance: R,
+    /// The maximum number of iterations to perform.
+    pub max_iterations: usize,
+}
+
+impl<R: Float> Default for IterativeOptions<R> {
+    /// Returns a tolerance of the square root of the machine epsilon and at most 1000
+    /// iterations.
+    fn default() -> Self {
+        IterativeOptions {
+            tolerance: R::epsilon().sqrt(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+/// The approximate solution of a linear system found by an iterative solver.
+#[derive(Clone, Debug)]
+pub struct IterativeSolution<K: Modulus> {
+    /// The approximate solution.
+    pub x: Vector<K>,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The relative residual norm before the first iteration and after each of them.
+    pub residuals: Vec<K::Real>,
+    /// Whether the tolerance was reached within the maximum number of iterations, the
+    /// last iterate being returned either way.
+    pub converged: bool,
+}
+
+/// Solves the linear system `a * x = b` with the Jacobi method, starting from zero.
+///
+/// Every sweep updates all the unknowns from the previous iterate, which converges when
+/// `a` is strictly diagonally dominant. Only the diagonal of `a` and its action on
+/// vectors are needed, so `a` need not be stored as a matrix.
+///
+/// # Arguments
+///
+/// * `a` - The square operator of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `options` - The stopping criteria.
+///
+/// # Returns
+///
+/// The solution found, which is not `converged` if the tolerance is not reached in time,
+/// a `MatrixError::NotSquare` if `a` is not square, a `MatrixError::ShapeMismatch` if the
+/// size of `b` differs from the number of rows of `a`, or a `MatrixError::Singular` if
+/// `a` has a zero on its diagonal.
+pub fn jacobi<K: ComplexField, A: DiagonalAccess<K> + ?Sized>(
+    a: &A,
+    b: &Vector<K>,
+    options: IterativeOptions<K::Real>,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    let diagonal = a.diagonal();
+    stationary(a, b, &diagonal, options, |x, residual| {
+        for (i, d) in diagonal.iter().enumerate() {
+            x[i] += residual[i].clone() / d.clone();
+        }
+    })
+}
+
+/// Solves the linear system `a * x = b` with the Gauss-Seidel method, starting from zero.
+///
+/// # Arguments
+///
+/// * `a` - The square matrix of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `options` - The stopping criteria.
+///
+/// # Returns
+///
+/// The solution found, or the errors of `sor`.
+pub fn gauss_seidel<K: ComplexField>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    options: IterativeOptions<K::Real>,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    sor(a, b, K::Real::one(), options)
+}
+
+/// Solves the linear system `a * x = b` with successive over-relaxation, starting from
+/// zero.
+///
+/// Every sweep updates the unknowns in order, each from the latest values of the others,
+/// and moves it by `omega` times the Gauss-Seidel step. It converges for any `omega`
+/// between zero and two when `a` is Hermitian positive definite. Unlike the other
+/// solvers, this one reads the entries of `a` row by row, so it takes a matrix rather
+/// than a `LinearOperator`.
+///
+/// # Arguments
+///
+/// * `a` - The square matrix of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `omega` - The relaxation factor, one giving the Gauss-Seidel method.
+/// * `options` - The stopping criteria.
+///
+/// # Returns
+///
+/// The solution found, which is not `converged` if the tolerance is not reached in time,
+/// a `MatrixError::NotSquare` if `a` is not square, a `MatrixError::ShapeMismatch` if the
+/// size of `b` differs from the number of rows of `a`, or a `MatrixError::Singular` if
+/// `a` has a zero on its diagonal.
+pub fn sor<K: ComplexField>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    omega: K::Real,
+    options: IterativeOptions<K::Real>,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    let omega = K::from_real(omega);
+    stationary(a, b, &a.diagonal(), options, |x, _| {
+        for i in 0..a.rows {
+            let sum = (0..a.cols)
+                .filter(|&j| j != i)
+                .fold(b[i].clone(), |res, j| {
+                    res - a[(i, j)].clone() * x[j].clone()
+                });
+            let step = sum / a[(i, i)].clone() - x[i].clone();
+            x[i] += omega.clone() * step;
+        }
+    })
+}
+
+/// Solves the linear system `a * x = b` with the preconditioned conjugate gradient
+/// method, starting from zero.
+///
+/// The operator must be Hermitian positive definite, and so must the preconditioner,
+/// which approximates the inverse of `a`.
+///
+/// # Arguments
+///
+/// * `a` - The square operator of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `preconditioner` - The operator applied to the residuals, if any.
+/// * `options` - The stopping criteria.
+///
+/// # Returns
+///
+/// The solution found, which is not `converged` if the tolerance is not reached in time,
+/// a `MatrixError::NotSquare` if `a` is not square, a `MatrixError::ShapeMismatch` if the
+/// size of `b` differs from the number of rows of `a`, or a
+/// `MatrixError::NotPositiveDefinite` if a search direction has a non-positive energy.
+pub fn conjugate_gradient<K: ComplexField, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: Option<&dyn LinearOperator<K>>,
+    options: IterativeOptions<K::Real>,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    check(a, b)?;
+    let norm_b = b.norm();
+    let mut x: Vector<K> = Vector::zeros(b.size());
+    if norm_b.is_zero() {
+        return Ok(solution(x, vec![K::Real::zero()], true));
+    }
+    let mut residuals = vec![K::Real::one()];
+    let mut r = b.clone();
+    let mut z = precondition(preconditioner, &r);
+    let mut p = z.clone();
+    let mut rz = inner(&r, &z);
+    for _ in 0..options.max_iterations {
+        let ap = a.apply(&p);
+        let energy = inner(&p, &ap);
+        if energy.real() <= K::Real::zero() {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+        let alpha = rz.clone() / energy;
+        x += p._scl(alpha.clone());
+        r -= ap._scl(alpha);
+        residuals.push(r.norm() / norm_b);
+        if residuals[residuals.len() - 1] <= options.tolerance {
+            return Ok(solution(x, residuals, true));
+        }
+        z = precondition(preconditioner, &r);
+        let next = inner(&r, &z);
+        let beta = next.clone() / rz;
+        p = &z + &p._scl(beta);
+        rz = next;
+    }
+    Ok(solution(x, residuals, false))
+}
+
+/// Solves the linear system `a * x = b` with the restarted generalized minimal residual
+/// method, starting from zero.
+///
+/// Each cycle builds an orthonormal basis of a Krylov subspace with the Arnoldi process
+/// and picks the update minimizing the residual over it. The preconditioner is applied
+/// on the right, so the residuals are the ones of the original system.
+///
+/// # Arguments
+///
+/// * `a` - The square operator of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `restart` - The number of iterations of a cycle, at least one.
+/// * `preconditioner` - The operator approximating the inverse of `a`, if any.
+/// * `options` - The stopping criteria.
+///
+/// # Returns
+///
+/// The solution found, which is not `converged` if the tolerance is not reached in time,
+/// a `MatrixError::NotSquare` if `a` is not square, a `MatrixError::ShapeMismatch` if
+/// the size of `b` differs from the number of rows of `a`, or a `MatrixError::Singular`
+/// if the Arnoldi process breaks down because `a` maps a basis vector into the span of
+/// the previous ones, which only happens when `a` is singular.
+pub fn gmres<K: ComplexField, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &Vector<K>,
+    restart: usize,
+    preconditioner: Option<&dyn LinearOperator<K>>,
+    options: IterativeOptions<K::Real>,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    check(a, b)?;
+    let restart = restart.max(1);
+    let norm_b = b.norm();
+    let mut x: Vector<K> = Vector::zeros(b.size());
+    if norm_b.is_zero() {
+        return Ok(solution(x, vec![K::Real::zero()], true));
+    }
+    let mut residuals = vec![K::Real::one()];
+    let mut r = b.clone();
+    loop {
+        let beta = r.norm();
+        let mut basis = vec![r._scl(K::from_real(beta.recip()))];
+        let mut h: Vec<Vec<K>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(K, K)> = Vec::with_capacity(restart);
+        let mut g = vec![K::from_real(beta)];
+        let mut exhausted = false;
+        for j in 0..restart {
+            if residuals.len() > options.max_iterations {
+                exhausted = true;
+                break;
+            }
+            let mut w = a.apply(&precondition(preconditioner, &basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            for v in basis.iter() {
+                let projection = inner(v, &w);
+                w -= v._scl(projection.clone());
+                column.push(projection);
+            }
+            let norm_w = w.norm();
+            column.push(K::from_real(norm_w));
+            for (i, (c, s)) in rotations.iter().enumerate() {
+                let (x, y) = (column[i].clone(), column[i + 1].clone());
+                column[i] = c.clone() * x.clone() + s.clone() * y.clone();
+                column[i + 1] = c.clone() * y - s.conjugate() * x;
+            }
+            let (c, s) = givens(column[j].clone(), column[j + 1].clone())
+                .unwrap_or_else(|| (K::one(), K::zero()));
+            column[j] = c.clone() * column[j].clone() + s.clone() * column[j + 1].clone();
+            if column[j].is_zero() {
+                return Err(MatrixError::Singular);
+            }
+            column.pop();
+            g.push(-s.conjugate() * g[j].clone());
+            g[j] = c.clone() * g[j].clone();
+            rotations.push((c, s));
+            h.push(column);
+            residuals.push(g[j + 1].modulus() / norm_b);
+            if residuals[residuals.len() - 1] <= options.tolerance || norm_w.is_zero() {
+                break;
+            }
+            basis.push(w._scl(K::from_real(norm_w.recip())));
+        }
+        let k = h.len();
+        let mut y: Vec<K> = g[..k].to_vec();
+        for i in (0..k).rev() {
+            for l in i + 1..k {
+                let value = h[l][i].clone() * y[l].clone();
+                y[i] -= value;
+            }
+            y[i] = y[i].clone() / h[i][i].clone();
+        }
+        let update = (0..k).fold(Vector::zeros(b.size()), |res: Vector<K>, l| {
+            &res + &basis[l]._scl(y[l].clone())
+        });
+        x += precondition(preconditioner, &update);
+        r = b - &a.apply(&x);
+        if r.norm() / norm_b <= options.tolerance {
+            return Ok(solution(x, residuals, true));
+        }
+        if exhausted {
+            return Ok(solution(x, residuals, false));
+        }
+    }
+}
+
+/// Runs a stationary iteration, starting from zero, until the residual is small enough.
+///
+/// # Arguments
+///
+/// * `a` - The square operator of the system.
+/// * `b` - The right-hand side, with as many elements as `a` has rows.
+/// * `diagonal` - The diagonal of `a`, which the iteration divides by.
+/// * `options` - The stopping criteria.
+/// * `sweep` - The update of the iterate performed by each iteration, given the residual
+///   `b - a * x` of the iterate, which is computed once per iteration.
+///
+/// # Returns
+///
+/// The solution found, or the errors of `sor`.
+fn stationary<
+    K: ComplexField,
+    A: LinearOperator<K> + ?Sized,
+    F: FnMut(&mut Vector<K>, &Vector<K>),
+>(
+    a: &A,
+    b: &Vector<K>,
+    diagonal: &Vector<K>,
+    options: IterativeOptions<K::Real>,
+    mut sweep: F,
+) -> Result<IterativeSolution<K>, MatrixError> {
+    check(a, b)?;
+    if diagonal.iter().any(|d| d.is_zero()) {
+        return Err(MatrixError::Singular);
+    }
+    let norm_b = b.norm();
+    let mut x: Vector<K> = Vector::zeros(b.size());
+    if norm_b.is_zero() {
+        return Ok(solution(x, vec![K::Real::zero()], true));
+    }
+    let mut residuals = vec![K::Real::one()];
+    let mut residual = b.clone();
+    for _ in 0..options.max_iterations {
+        sweep(&mut x, &residual);
+        residual = b - &a.apply(&x);
+        residuals.push(residual.norm() / norm_b);
+        if residuals[residuals.len() - 1] <= options.tolerance {
+            return Ok(solution(x, residuals, true));
+        }
+    }
+    Ok(solution(x, residuals, false))
+}
+
+/// Checks that an operator is square and matches the size of a right-hand side.
+///
+/// # Arguments
+///
+/// * `a` - The operator of the system.
+/// * `b` - The right-hand side.
+///
+/// # Returns
+///
+/// `Ok(())`, a `MatrixError::NotSquare` if `a` is not square, or a
+/// `MatrixError::ShapeMismatch` if the size of `b` differs from the number of rows of `a`.
+fn check<K, A: LinearOperator<K> + ?Sized>(a: &A, b: &Vector<K>) -> Result<(), MatrixError> {
+    let [cols, rows] = a.shape();
+    if cols != rows {
+        return Err(MatrixError::NotSquare { shape: a.shape() });
+    }
+    if b.size() != rows {
+        return Err(MatrixError::ShapeMismatch {
+            expected: [1, rows],
+            found: b.shape(),
+        });
+    }
+    Ok(())
+}
+
+/// Wraps an iterate and its residual history into a solution.
+///
+/// # Arguments
+///
+/// * `x` - The iterate.
+/// * `residuals` - The relative residual norms, starting before the first iteration.
+/// * `converged` - Whether the tolerance was reached.
+///
+/// # Returns
+///
+/// The solution, whose number of iterations is the number of residuals after the first.
+fn solution<K: Modulus>(
+    x: Vector<K>,
+    residuals: Vec<K::Real>,
+    converged: bool,
+) -> IterativeSolution<K> {
+    IterativeSolution {
+        x,
+        iterations: residuals.len() - 1,
+        residuals,
+        converged,
+    }
+}
+
+/// Applies an optional preconditioner to a vector.
+///
+/// # Arguments
+///
+/// * `preconditioner` - The preconditioner, if any.
+/// * `x` - The vector.
+///
+/// # Returns
+///
+/// The image of the vector, or a copy of it without a preconditioner.
+fn precondition<K: Clone>(
+    preconditioner: Option<&dyn LinearOperator<K>>,
+    x: &Vector<K>,
+) -> Vector<K> {
+    preconditioner.map_or_else(|| x.clone(), |m| m.apply(x))
+}
+
+/// Computes the inner product `uᴴ * v`, conjugating the first vector.
+///
+/// # Arguments
+///
+/// * `u` - The conjugated vector.
+/// * `v` - The other vector, of the same size.
+///
+/// # Returns
+///
+/// The inner product of the two vectors.
+fn inner<K: ComplexField>(u: &Vector<K>, v: &Vector<K>) -> K {
+    (0..u.size()).fold(K::zero(), |res, i| {
+        u[i].conjugate().mul_add(v[i].clone(), res)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vector_approx_eq, Complex};
+
+    /// The one-dimensional Laplacian with Dirichlet boundaries, without its matrix.
+    struct Laplacian(usize);
+
+    impl LinearOperator<f64> for Laplacian {
+        fn shape(&self) -> [usize; 2] {
+            [self.0, self.0]
+        }
+
+        fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+            (0..self.0)
+                .map(|i| {
+                    let left = if i > 0 { x[i - 1] } else { 0. };
+                    let right = if i + 1 < self.0 { x[i + 1] } else { 0. };
+                    2. * x[i] - left - right
+                })
+                .collect()
+        }
+    }
+
+    impl DiagonalAccess<f64> for Laplacian {
+        fn diagonal(&self) -> Vector<f64> {
+            Vector::from(vec![2.; self.0])
+        }
+    }
+
+    fn options(tolerance: f64) -> IterativeOptions<f64> {
+        IterativeOptions {
+            tolerance,
+            max_iterations: 500,
+        }
+    }
+
+    fn system() -> (Matrix<f64>, Vector<f64>, Vector<f64>) {
+        let a = Matrix::from([
+            [4., -1., 0., 1.],
+            [-1., 4., -1., 0.],
+            [0., -1., 4., -1.],
+            [1., 0., -1., 4.],
+        ]);
+        let x = Vector::from([1., -2., 3., 0.5]);
+        let b = a.mul_vec(&x);
+        (a, b, x)
+    }
+
+    #[test]
+    fn test_stationary() {
+        let (a, b, x) = system();
+        let first = jacobi(&a, &b, options(1e-12)).unwrap();
+        assert!(first.converged);
+        assert_vector_approx_eq!(first.x, x, abs = 1e-10);
+        assert_eq!(first.residuals.len(), first.iterations + 1);
+        assert!(first.residuals[first.iterations] <= 1e-12);
+        let second = gauss_seidel(&a, &b, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(second.x, x, abs = 1e-10);
+        assert!(second.iterations < first.iterations);
+        let relaxed = sor(&a, &b, 1.1, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(relaxed.x, x, abs = 1e-10);
+        let laplacian = Laplacian(5);
+        let ones: Vector<f64> = Vector::from(vec![1.; 5]);
+        let operator = jacobi(&laplacian, &ones, options(1e-10)).unwrap();
+        assert!(operator.converged);
+        assert_vector_approx_eq!(
+            operator.x,
+            Vector::from([2.5, 4., 4.5, 4., 2.5]),
+            abs = 1e-8
+        );
+        let zero = jacobi(&a, &Vector::zeros(4), options(1e-12)).unwrap();
+        assert_eq!((zero.iterations, zero.x), (0, Vector::zeros(4)));
+        assert_eq!(zero.residuals, vec![0.]);
+        let defaults = IterativeOptions::<f64>::default();
+        assert_eq!(defaults.max_iterations, 1000);
+        let relaxed = sor(&a, &b, 1.1, defaults).unwrap();
+        assert!(relaxed.residuals[relaxed.iterations] <= defaults.tolerance);
+    }
+
+    #[test]
+    fn test_stationary_errors() {
+        let b = Vector::from([1., 1.]);
+        let diverged = jacobi(&Matrix::from([[1., 2.], [2., 1.]]), &b, options(1e-12)).unwrap();
+        assert!(!diverged.converged);
+        assert_eq!(diverged.iterations, 500);
+        assert_eq!(diverged.residuals.len(), 501);
+        assert_eq!(
+            gauss_seidel(&Matrix::from([[0., 1.], [1., 0.]]), &b, options(1e-12)).err(),
+            Some(MatrixError::Singular)
+        );
+        assert_eq!(
+            sor(&Matrix::from([[1., 2.]]), &b, 1., options(1e-12)).err(),
+            Some(MatrixError::NotSquare { shape: [2, 1] })
+        );
+        assert_eq!(
+            jacobi(&Matrix::identity(3), &b, options(1e-12)).err(),
+            Some(MatrixError::ShapeMismatch {
+                expected: [1, 3],
+                found: [1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_conjugate_gradient() {
+        let (a, b, x) = system();
+        let cg = conjugate_gradient(&a, &b, None, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(cg.x, x, abs = 1e-10);
+        assert!(cg.iterations <= 5);
+        let diagonal = Matrix::from([
+            [0.25, 0., 0., 0.],
+            [0., 0.25, 0., 0.],
+            [0., 0., 0.25, 0.],
+            [0., 0., 0., 0.25],
+        ]);
+        let preconditioned = conjugate_gradient(&a, &b, Some(&diagonal), options(1e-12)).unwrap();
+        assert_vector_approx_eq!(preconditioned.x, x, abs = 1e-10);
+        let laplacian = Laplacian(50);
+        let ones: Vector<f64> = Vector::from(vec![1.; 50]);
+        let cg = conjugate_gradient(&laplacian, &ones, None, options(1e-10)).unwrap();
+        assert!(cg.converged && cg.iterations <= 50);
+        let limit = IterativeOptions {
+            tolerance: 1e-10,
+            max_iterations: 5,
+        };
+        let truncated = conjugate_gradient(&laplacian, &ones, None, limit).unwrap();
+        assert!(!truncated.converged);
+        assert_eq!(truncated.residuals.len(), 6);
+        assert_vector_approx_eq!(laplacian.apply(&cg.x), ones, abs = 1e-8);
+        let negative = Matrix::from([[-1., 0.], [0., -2.]]);
+        assert_eq!(
+            conjugate_gradient(&negative, &Vector::from([1., 1.]), None, options(1e-12)).err(),
+            Some(MatrixError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_gmres() {
+        let a = Matrix::from([[3., 1., 0.], [-1., 2., 2.], [0., 4., 5.]]);
+        let x = Vector::from([1., 2., -1.]);
+        let b = a.mul_vec(&x);
+        let full = gmres(&a, &b, 3, None, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(full.x, x, abs = 1e-10);
+        assert!(full.iterations <= 3);
+        let restarted = gmres(&a, &b, 1, None, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(restarted.x, x, abs = 1e-10);
+        assert!(restarted.residuals.windows(2).all(|w| w[1] <= w[0] + 1e-15));
+        let inverse = a.inverse();
+        let preconditioned = gmres(&a, &b, 3, Some(&inverse), options(1e-12)).unwrap();
+        assert_vector_approx_eq!(preconditioned.x, x, abs = 1e-10);
+        assert_eq!(preconditioned.iterations, 1);
+        let rotation = Matrix::from([[0., 1.], [-1., 0.]]);
+        let stalled = gmres(&rotation, &Vector::from([1., 0.]), 1, None, options(1e-12)).unwrap();
+        assert!(!stalled.converged);
+        assert_eq!(stalled.iterations, 500);
+        assert!(stalled.residuals.iter().all(|&r| r == 1.));
+    }
+
+    #[test]
+    fn test_gmres_breakdown() {
+        let b = Vector::from([1., 0.]);
+        assert_eq!(
+            gmres(&Matrix::zeros(2, 2), &b, 2, None, options(1e-12)).err(),
+            Some(MatrixError::Singular)
+        );
+        let a = Matrix::from([[1., 0.], [0., 0.]]);
+        let consistent = gmres(&a, &b, 2, None, options(1e-12)).unwrap();
+        assert!(consistent.converged);
+        assert_eq!(consistent.x, b);
+    }
+
+    #[test]
+    fn test_gmres_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([
+            [c(2., 1.), c(0., 1.), c(1., 0.)],
+            [c(1., 0.), c(3., -1.), c(0., 0.)],
+            [c(0., -1.), c(1., 1.), c(4., 0.)],
+        ]);
+        let x = Vector::from([c(1., 0.), c(0., -1.), c(2., 1.)]);
+        let b = a.mul_vec(&x);
+        let solution = gmres(&a, &b, 3, None, options(1e-12)).unwrap();
+        assert_vector_approx_eq!(solution.x, x, abs = 1e-10);
+    }
+}
@@ -0,0 +1,4 @@
+//! Solvers for linear systems `A * x = b`.
+
+pub mod direct;
+pub mod iterative;
@@ -0,0 +1,202 @@
+use crate::{ex09::Conjugate, ex10::gauss_jordan, matrix::Matrix, scalar::Field, vector::Vector};
+
+impl<K: Field> Matrix<K> {
+    /// Converts the matrix to its reduced row echelon form, keeping track of the pivots.
+    ///
+    /// The pivot of each column is chosen by the default pivot strategy of the field, and
+    /// a column whose candidate pivot is negligible is treated as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// The reduced row echelon form of the matrix, whose first `pivots.len()` rows are
+    /// non-zero, and the columns holding the pivots, in increasing order.
+    pub fn row_echelon_pivots(&self, tolerance: f64) -> (Matrix<K>, Vec<usize>) {
+        let mut data = self.clone();
+        let pivots = gauss_jordan::<K, K::Pivot>(&mut data, self.cols, tolerance);
+        (data, pivots)
+    }
+
+    /// Computes the default tolerance for eliminating the matrix.
+    ///
+    /// # Returns
+    ///
+    /// Zero for exact fields, and the machine epsilon scaled by the largest dimension and
+    /// the largest modulus of the entries for floating point ones.
+    pub fn default_tolerance(&self) -> f64 {
+        K::default_tolerance(&self.data, self.rows.max(self.cols))
+    }
+
+    /// Computes a basis of the null space, the vectors `x` such that `self * x = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// One vector for each column without a pivot, set to one in that column and zero in
+    /// the other free columns.
+    pub fn null_space(&self, tolerance: f64) -> Vec<Vector<K>> {
+        let (rref, pivots) = self.row_echelon_pivots(tolerance);
+        null_basis(&rref, &pivots, self.cols)
+    }
+
+    /// Computes a basis of the column space, the vectors `self * x`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// The columns of the matrix that hold a pivot.
+    pub fn column_space(&self, tolerance: f64) -> Vec<Vector<K>> {
+        let (_, pivots) = self.row_echelon_pivots(tolerance);
+        pivots.iter().map(|&j| self.col(j).to_vector()).collect()
+    }
+
+    /// Computes a basis of the row space, spanned by the rows of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// The non-zero rows of the reduced row echelon form of the matrix.
+    pub fn row_space(&self, tolerance: f64) -> Vec<Vector<K>> {
+        let (rref, pivots) = self.row_echelon_pivots(tolerance);
+        (0..pivots.len()).map(|i| rref.row(i).to_vector()).collect()
+    }
+
+    /// Computes a basis of the left null space, the vectors `y` such that `yᴴ * self = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest modulus that counts as zero, ignored by exact fields.
+    ///
+    /// # Returns
+    ///
+    /// A basis of the null space of the conjugate transpose of the matrix.
+    pub fn left_null_space(&self, tolerance: f64) -> Vec<Vector<K>>
+    where
+        K: Conjugate,
+    {
+        self.transpose().null_space(tolerance)
+    }
+}
+
+/// Reads a basis of the null space off a reduced row echelon form.
+///
+/// # Arguments
+///
+/// * `rref` - The reduced row echelon form.
+/// * `pivots` - The columns holding the pivots.
+/// * `n` - The number of leading columns of `rref` the null space is taken over.
+///
+/// # Returns
+///
+/// One vector for each of the `n` leading columns without a pivot, set to one in that
+/// column and zero in the other free columns.
+pub(crate) fn null_basis<K: Field>(rref: &Matrix<K>, pivots: &[usize], n: usize) -> Vec<Vector<K>> {
+    (0..n)
+        .filter(|j| !pivots.contains(j))
+        .map(|free| {
+            let mut x = Vector::zeros(n);
+            x[free] = K::one();
+            for (i, &j) in pivots.iter().enumerate() {
+                x[j] = -rref[(i, free)].clone();
+            }
+            x
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Complex;
+    use num::rational::Ratio;
+
+    #[test]
+    fn test_row_echelon_pivots() {
+        let a = Matrix::from([[1., 2., 1.], [2., 4., 0.], [3., 6., 1.]]);
+        let (rref, pivots) = a.row_echelon_pivots(1e-12);
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(
+            rref,
+            Matrix::from([[1., 2., 0.], [0., 0., 1.], [0., 0., 0.]])
+        );
+        let a = Matrix::from([[1., 1.], [1., 1. + 1e-15]]);
+        assert_eq!(a.row_echelon_pivots(1e-12).1, vec![0]);
+        assert_eq!(a.row_echelon_pivots(0.).1, vec![0, 1]);
+        assert_eq!(a.default_tolerance(), 2. * (1. + 1e-15) * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_subspaces() {
+        let a = Matrix::from([[1., 2., 0., 1.], [2., 4., 1., 4.], [3., 6., 1., 5.]]);
+        let kernel = a.null_space(1e-12);
+        assert_eq!(
+            kernel,
+            vec![
+                Vector::from([-2., 1., 0., 0.]),
+                Vector::from([-1., 0., -2., 1.])
+            ]
+        );
+        for x in kernel.iter() {
+            assert_eq!(&a * x, Vector::zeros(3));
+        }
+        assert_eq!(
+            a.column_space(1e-12),
+            vec![Vector::from([1., 2., 3.]), Vector::from([0., 1., 1.])]
+        );
+        assert_eq!(
+            a.row_space(1e-12),
+            vec![
+                Vector::from([1., 2., 0., 1.]),
+                Vector::from([0., 0., 1., 2.])
+            ]
+        );
+        let left = a.left_null_space(1e-12);
+        assert_eq!(left, vec![Vector::from([-1., -1., 1.])]);
+        assert!(Matrix::<f64>::identity(3).null_space(1e-12).is_empty());
+        assert_eq!(Matrix::<f64>::zeros(2, 2).column_space(1e-12), vec![]);
+    }
+
+    #[test]
+    fn test_subspaces_rational() {
+        let r = Ratio::new;
+        let a = Matrix::from([
+            [r(1, 3), r(1, 7), r(1, 11)],
+            [r(1, 5), r(1, 9), r(1, 13)],
+            [r(2, 3), r(2, 7), r(2, 11)],
+        ]);
+        let kernel = a.null_space(0.);
+        assert_eq!(kernel.len(), 1);
+        assert_eq!(&a * &kernel[0], Vector::from([r(0, 1), r(0, 1), r(0, 1)]));
+        let left = a.left_null_space(0.);
+        assert_eq!(left, vec![Vector::from([r(-2, 1), r(0, 1), r(1, 1)])]);
+        assert_eq!(a.row_space(0.).len(), 2);
+        let a = Matrix::from([
+            [r(1, 1), r(1, 1)],
+            [r(1, 1), r(1_000_000_001, 1_000_000_000)],
+        ]);
+        assert!(a.null_space(1e-6).is_empty());
+    }
+
+    #[test]
+    fn test_subspaces_complex() {
+        let c = |re: f64, im| Complex(num::Complex::new(re, im));
+        let a = Matrix::from([[c(1., 0.), c(0., 1.)], [c(0., 1.), c(-1., 0.)]]);
+        let kernel = a.null_space(1e-12);
+        assert_eq!(kernel, vec![Vector::from([c(0., -1.), c(1., 0.)])]);
+        let left = a.left_null_space(1e-12);
+        assert_eq!(left.len(), 1);
+        assert_eq!(&a.transpose() * &left[0], Vector::zeros(2));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{error::MatrixError, scalar::Scalar, vector::Vector, Equals};
+
+/// A generic Vector struct whose size is known at compile time and whose data lives on
+/// the stack.
+#[derive(Clone, Copy, Debug)]
+pub struct SVector<K, const N: usize> {
+    /// The data of the SVector.
+    pub data: [K; N],
+}
+
+impl<K, const N: usize> From<[K; N]> for SVector<K, N> {
+    /// Converts a fixed-size array into a SVector.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An array of elements.
+    ///
+    /// # Returns
+    ///
+    /// A SVector containing the elements of the array.
+    fn from(value: [K; N]) -> Self {
+        SVector { data: value }
+    }
+}
+
+impl<K: Clone, const N: usize> From<SVector<K, N>> for Vector<K> {
+    /// Converts a SVector into a dynamically sized Vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A SVector.
+    ///
+    /// # Returns
+    ///
+    /// A Vector containing the elements of the SVector.
+    fn from(value: SVector<K, N>) -> Self {
+        Vector::from(value.data)
+    }
+}
+
+impl<K: Clone, const N: usize> TryFrom<&Vector<K>> for SVector<K, N> {
+    type Error = MatrixError;
+
+    /// Converts a dynamically sized Vector into a SVector.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A Vector.
+    ///
+    /// # Returns
+    ///
+    /// A SVector containing the elements of the Vector, or a `MatrixError::ShapeMismatch`
+    /// if the Vector does not have exactly `N` elements.
+    fn try_from(value: &Vector<K>) -> Result<Self, Self::Error> {
+        let data = value
+            .data
+            .clone()
+            .try_into()
+            .map_err(|_| MatrixError::ShapeMismatch {
+                expected: [1, N],
+                found: value.shape(),
+            })?;
+        Ok(SVector { data })
+    }
+}
+
+impl<K: Equals, const N: usize> PartialEq for SVector<K, N> {
+    /// Checks if two SVectors are equal by comparing their elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another SVector to compare with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all elements are equal, `false` otherwise.
+    fn eq(&self, v: &Self) -> bool {
+        self.data
+            .iter()
+            .zip(v.data.iter())
+            .all(|(a, b)| a.equals(b))
+    }
+}
+
+impl<K, const N: usize> SVector<K, N> {
+    /// Returns the size of the SVector.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the SVector.
+    pub fn size(&self) -> usize {
+        N
+    }
+}
+
+impl<K: Scalar, const N: usize> SVector<K, N> {
+    /// Adds two vectors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector of the same size to add.
+    ///
+    /// # Returns
+    ///
+    /// A new vector that is the element-wise sum of the two vectors.
+    pub fn _add(&self, v: &SVector<K, N>) -> Self {
+        SVector {
+            data: std::array::from_fn(|i| self.data[i].clone() + v.data[i].clone()),
+        }
+    }
+
+    /// Subtracts one vector from another element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector of the same size to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new vector that is the element-wise difference of the two vectors.
+    pub fn _sub(&self, v: &SVector<K, N>) -> Self {
+        SVector {
+            data: std::array::from_fn(|i| self.data[i].clone() - v.data[i].clone()),
+        }
+    }
+
+    /// Multiplies a vector by a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The scalar to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new vector that is the original vector scaled by the scalar.
+    pub fn _scl<F>(&self, a: F) -> Self
+    where
+        K: std::ops::Mul<F, Output = K>,
+        F: Clone,
+    {
+        SVector {
+            data: std::array::from_fn(|i| self.data[i].clone() * a.clone()),
+        }
+    }
+
+    /// Computes the dot product of two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another vector of the same size.
+    ///
+    /// # Returns
+    ///
+    /// The dot product of the two vectors.
+    pub fn dot(&self, v: &SVector<K, N>) -> K {
+        let mut res = K::zero();
+        for i in 0..N {
+            res = v.data[i].clone().mul_add(self.data[i].clone(), res);
+        }
+        res
+    }
+}
+
+impl<K: Scalar> SVector<K, 3> {
+    /// Computes the cross product of two three-dimensional vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The second vector.
+    ///
+    /// # Returns
+    ///
+    /// The cross product of the two vectors.
+    pub fn cross_product(&self, v: &SVector<K, 3>) -> SVector<K, 3> {
+        let (u, v) = (&self.data, &v.data);
+        let minor = |i: usize, j: usize| u[i].clone() * v[j].clone() - u[j].clone() * v[i].clone();
+        SVector {
+            data: [minor(1, 2), minor(2, 0), minor(0, 1)],
+        }
+    }
+}
+
+impl<K: Scalar, const N: usize> std::ops::Add for SVector<K, N> {
+    /// Adds two SVectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The SVector to add.
+    ///
+    /// # Returns
+    ///
+    /// A new SVector with the result of the operation.
+    fn add(self, rhs: Self) -> Self::Output {
+        self._add(&rhs)
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar, const N: usize> std::ops::Sub for SVector<K, N> {
+    /// Subtracts two SVectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The SVector to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new SVector with the result of the operation.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self._sub(&rhs)
+    }
+
+    type Output = Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub_scl() {
+        let u = SVector::from([2., 3.]);
+        let v = SVector::from([5., 7.]);
+        assert_eq!(u + v, SVector::from([7., 10.]));
+        assert_eq!(u - v, SVector::from([-3., -4.]));
+        assert_eq!(u._scl(2.), SVector::from([4., 6.]));
+    }
+
+    #[test]
+    fn test_dot_cross_product() {
+        let u = SVector::from([4., 2., -3.]);
+        let v = SVector::from([-2., -5., 16.]);
+        assert_eq!(u.dot(&v), -66.);
+        assert_eq!(u.cross_product(&v), SVector::from([17., -58., -16.]));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let u = SVector::from([1., 2., 3.]);
+        let v = Vector::from(u);
+        assert_eq!(v.data, vec![1., 2., 3.]);
+        let w: SVector<f64, 3> = SVector::try_from(&v).unwrap();
+        assert_eq!(w.data, [1., 2., 3.]);
+        assert_eq!(
+            SVector::<f64, 2>::try_from(&v),
+            Err(MatrixError::ShapeMismatch {
+                expected: [1, 2],
+                found: [1, 3]
+            })
+        );
+    }
+}
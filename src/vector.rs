@@ -1,4 +1,6 @@
-use crate::Equals;
+use num::{bigint::BigInt, rational::Ratio};
+
+use crate::{error::MatrixError, scalar::Scalar, Complex, Equals};
 
 /// A generic Vector struct that holds a 1D vector of data.
 #[derive(Clone, Debug)]
@@ -48,9 +50,15 @@ impl<K: Equals> PartialEq for Vector<K> {
     ///
     /// # Returns
     ///
-    /// `true` if all elements are equal, `false` otherwise.
+    /// `true` if the Vectors have the same size and all elements are equal, `false`
+    /// otherwise.
     fn eq(&self, v: &Self) -> bool {
-        v.data.iter().zip(v.data.iter()).all(|(a, b)| a.equals(b))
+        self.is_same_size(v)
+            && self
+                .data
+                .iter()
+                .zip(v.data.iter())
+                .all(|(a, b)| a.equals(b))
     }
 }
 
@@ -76,6 +84,160 @@ impl<K> Vector<K> {
     pub fn is_same_size(&self, v: &Vector<K>) -> bool {
         self.size() == v.size()
     }
+
+    /// Returns the shape of the Vector seen as a single column matrix.
+    ///
+    /// # Returns
+    ///
+    /// An array containing the number of columns and rows, as in `Matrix::shape`.
+    pub(crate) fn shape(&self) -> [usize; 2] {
+        [1, self.size()]
+    }
+
+    /// Iterates over the elements of the Vector.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self.data.iter()
+    }
+
+    /// Iterates mutably over the elements of the Vector.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, K> {
+        self.data.iter_mut()
+    }
+}
+
+impl<K: Clone + Default> Vector<K> {
+    /// Creates a Vector filled with zeroes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements.
+    ///
+    /// # Returns
+    ///
+    /// A Vector of `n` elements that are all `K::default()`.
+    pub fn zeros(n: usize) -> Self {
+        Vector {
+            data: vec![K::default(); n],
+        }
+    }
+
+    /// Creates a vector of the canonical basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The position of the one.
+    /// * `n` - The number of elements.
+    ///
+    /// # Returns
+    ///
+    /// A Vector of `n` elements that is one at position `i` and zero elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `n`.
+    pub fn basis(i: usize, n: usize) -> Self
+    where
+        K: Scalar,
+    {
+        let mut res = Vector::zeros(n);
+        res[i] = K::one();
+        res
+    }
+}
+
+impl<K> std::ops::Index<usize> for Vector<K> {
+    type Output = K;
+
+    /// Returns a reference to an element of the Vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The position of the element.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.data[i]
+    }
+}
+
+impl<K> std::ops::IndexMut<usize> for Vector<K> {
+    /// Returns a mutable reference to an element of the Vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The position of the element.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.data[i]
+    }
+}
+
+impl<K> IntoIterator for Vector<K> {
+    type Item = K;
+    type IntoIter = std::vec::IntoIter<K>;
+
+    /// Consumes the Vector into an iterator over its elements.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a Vector<K> {
+    type Item = &'a K;
+    type IntoIter = std::slice::Iter<'a, K>;
+
+    /// Iterates over references to the elements of the Vector.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a mut Vector<K> {
+    type Item = &'a mut K;
+    type IntoIter = std::slice::IterMut<'a, K>;
+
+    /// Iterates over mutable references to the elements of the Vector.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+impl<K> FromIterator<K> for Vector<K> {
+    /// Collects the elements of an iterator into a Vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The elements of the Vector.
+    ///
+    /// # Returns
+    ///
+    /// A Vector containing the collected elements.
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Vector {
+            data: iter.into_iter().collect(),
+        }
+    }
 }
 
 impl<K: std::fmt::Debug> std::fmt::Display for Vector<K> {
@@ -112,16 +274,99 @@ impl<K: Copy + Default + num_traits::ops::mul_add::MulAdd<f32, K, Output = K>>
     /// # Returns
     ///
     /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two Vectors do not have the same size.
     fn mul_add(self, a: f32, b: Self) -> Self::Output {
-        let mut res = Vector::from(vec![K::default(); self.size()]);
-        for i in 0..self.size() {
-            res.data[i] = self.data[i].mul_add(a, b.data[i]);
+        crate::ex02::TryMulAdd::try_mul_add(self, a, b).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<K: Scalar> std::ops::Add for Vector<K> {
+    /// Adds two Vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to add.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn add(self, rhs: Self) -> Self::Output {
+        self._add(&rhs)
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar> std::ops::Add for &Vector<K> {
+    /// Adds two Vectors without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to add.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn add(self, rhs: Self) -> Self::Output {
+        self._add(rhs)
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::AddAssign<&Vector<K>> for Vector<K> {
+    /// Adds another Vector to this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to add.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn add_assign(&mut self, rhs: &Vector<K>) {
+        if !self.is_same_size(rhs) {
+            panic!(
+                "{}",
+                MatrixError::ShapeMismatch {
+                    expected: self.shape(),
+                    found: rhs.shape(),
+                }
+            );
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a += b.clone();
         }
-        res
     }
 }
 
-impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Vector<K> {
+impl<K: Scalar> std::ops::AddAssign for Vector<K> {
+    /// Adds another Vector to this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to add.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn add_assign(&mut self, rhs: Vector<K>) {
+        *self += &rhs;
+    }
+}
+
+impl<K: Scalar> std::ops::Sub for Vector<K> {
     /// Subtracts two Vectors.
     ///
     /// # Arguments
@@ -131,6 +376,10 @@ impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Vector<K>
     /// # Returns
     ///
     /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
     fn sub(self, rhs: Self) -> Self::Output {
         self._sub(&rhs)
     }
@@ -138,26 +387,253 @@ impl<K: Copy + Default + std::ops::Sub<Output = K>> std::ops::Sub for Vector<K>
     type Output = Self;
 }
 
-impl<K: Copy + Default + std::ops::Mul<F, Output = K>, F: Copy> std::ops::Mul<F> for Vector<K> {
-    /// Multiplies a Vectors by a scalar.
+impl<K: Scalar> std::ops::Sub for &Vector<K> {
+    /// Subtracts two Vectors without consuming them.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self._sub(rhs)
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::SubAssign<&Vector<K>> for Vector<K> {
+    /// Subtracts another Vector from this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to subtract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn sub_assign(&mut self, rhs: &Vector<K>) {
+        if !self.is_same_size(rhs) {
+            panic!(
+                "{}",
+                MatrixError::ShapeMismatch {
+                    expected: self.shape(),
+                    found: rhs.shape(),
+                }
+            );
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a -= b.clone();
+        }
+    }
+}
+
+impl<K: Scalar> std::ops::SubAssign for Vector<K> {
+    /// Subtracts another Vector from this one in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The Vector to subtract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands are not the same size.
+    fn sub_assign(&mut self, rhs: Vector<K>) {
+        *self -= &rhs;
+    }
+}
+
+impl<K: Scalar> std::ops::Neg for Vector<K> {
+    /// Negates every element of a Vector.
+    ///
+    /// # Returns
+    ///
+    /// The Vector with the opposite elements.
+    fn neg(mut self) -> Self::Output {
+        for a in self.data.iter_mut() {
+            *a = -a.clone();
+        }
+        self
+    }
+
+    type Output = Self;
+}
+
+impl<K: Scalar> std::ops::Neg for &Vector<K> {
+    /// Negates every element of a Vector without consuming it.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the opposite elements.
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::Mul<K> for Vector<K> {
+    /// Multiplies a Vector by a scalar.
     ///
     /// # Arguments
     ///
-    /// * `rhs` - The scalar to multiply the vector by.
+    /// * `rhs` - The scalar to multiply by.
     ///
     /// # Returns
     ///
     /// A new Vector with the result of the operation.
-    fn mul(self, rhs: F) -> Self::Output {
+    fn mul(self, rhs: K) -> Self::Output {
         self._scl(rhs)
     }
 
     type Output = Self;
 }
 
+impl<K: Scalar> std::ops::Mul<K> for &Vector<K> {
+    /// Multiplies a Vector by a scalar without consuming it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The scalar to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new Vector with the result of the operation.
+    fn mul(self, rhs: K) -> Self::Output {
+        self._scl(rhs)
+    }
+
+    type Output = Vector<K>;
+}
+
+impl<K: Scalar> std::ops::MulAssign<K> for Vector<K> {
+    /// Multiplies a Vector by a scalar in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The scalar to multiply by.
+    fn mul_assign(&mut self, rhs: K) {
+        for a in self.data.iter_mut() {
+            *a *= rhs.clone();
+        }
+    }
+}
+
+/// Implements the multiplication of a Vector on the left by each of the given scalar types,
+/// which the orphan rule does not allow generically.
+macro_rules! impl_scalar_mul {
+    ($($scalar:ty),*) => {$(
+        impl std::ops::Mul<Vector<$scalar>> for $scalar {
+            /// Multiplies a scalar by a Vector.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The Vector to multiply.
+            ///
+            /// # Returns
+            ///
+            /// A new Vector with the result of the operation.
+            fn mul(self, rhs: Vector<$scalar>) -> Self::Output {
+                rhs._scl(self)
+            }
+
+            type Output = Vector<$scalar>;
+        }
+
+        impl std::ops::Mul<&Vector<$scalar>> for $scalar {
+            /// Multiplies a scalar by a Vector without consuming it.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The Vector to multiply.
+            ///
+            /// # Returns
+            ///
+            /// A new Vector with the result of the operation.
+            fn mul(self, rhs: &Vector<$scalar>) -> Self::Output {
+                rhs._scl(self)
+            }
+
+            type Output = Vector<$scalar>;
+        }
+    )*};
+}
+
+impl_scalar_mul!(
+    f32,
+    f64,
+    i32,
+    i64,
+    i128,
+    Complex<f32>,
+    Complex<f64>,
+    Ratio<i64>,
+    Ratio<BigInt>
+);
+
+/// Implements the multiplication of a Vector of complex numbers by a real scalar of the same
+/// precision.
+macro_rules! impl_real_mul {
+    ($($complex:ty => $real:ty),*) => {$(
+        impl std::ops::Mul<$real> for Vector<$complex> {
+            /// Multiplies a Vector by a real scalar.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// A new Vector with the result of the operation.
+            fn mul(self, rhs: $real) -> Self::Output {
+                self * <$complex>::from(rhs)
+            }
+
+            type Output = Self;
+        }
+
+        impl std::ops::Mul<$real> for &Vector<$complex> {
+            /// Multiplies a Vector by a real scalar without consuming it.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// A new Vector with the result of the operation.
+            fn mul(self, rhs: $real) -> Self::Output {
+                self * <$complex>::from(rhs)
+            }
+
+            type Output = Vector<$complex>;
+        }
+
+        impl std::ops::MulAssign<$real> for Vector<$complex> {
+            /// Multiplies a Vector by a real scalar in place.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs` - The scalar to multiply by.
+            fn mul_assign(&mut self, rhs: $real) {
+                *self *= <$complex>::from(rhs);
+            }
+        }
+    )*};
+}
+
+impl_real_mul!(Complex<f32> => f32, Complex<f64> => f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modular::Modular;
 
     #[test]
     fn test_size() {
@@ -166,4 +642,62 @@ mod tests {
         assert_eq!(v1.size(), 3);
         assert_eq!(v2.size(), 1);
     }
+
+    #[test]
+    fn test_eq() {
+        let v = Vector::from([1., 2.]);
+        assert_eq!(v, Vector::from([1., 2.]));
+        assert_ne!(v, Vector::from([1., 3.]));
+        assert_ne!(v, Vector::from([1., 2., 0.]));
+    }
+
+    #[test]
+    fn test_index_and_iterators() {
+        let mut v: Vector<f64> = (1..4).map(|x| x as f64).collect();
+        assert_eq!(v[1], 2.);
+        v[1] = 5.;
+        for x in v.iter_mut() {
+            *x *= 2.;
+        }
+        for x in &mut v {
+            *x += 1.;
+        }
+        assert_eq!(v.iter().copied().collect::<Vec<f64>>(), vec![3., 11., 7.]);
+        assert_eq!((&v).into_iter().count(), 3);
+        assert_eq!(v.into_iter().sum::<f64>(), 21.);
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(Vector::<f64>::zeros(3).data, vec![0., 0., 0.]);
+        assert_eq!(Vector::<f64>::basis(1, 3).data, vec![0., 1., 0.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basis_out_of_bounds() {
+        Vector::<f64>::basis(3, 3);
+    }
+
+    #[test]
+    fn test_operators() {
+        let u = Vector::from([2., 3.]);
+        let v = Vector::from([5., 7.]);
+        assert_eq!(&u + &v, Vector::from([7., 10.]));
+        assert_eq!(&u - &v, Vector::from([-3., -4.]));
+        assert_eq!(-&u, Vector::from([-2., -3.]));
+        assert_eq!(&u * 2., Vector::from([4., 6.]));
+        let mut w = u.clone();
+        w += &v;
+        w -= u.clone();
+        w *= 2.;
+        assert_eq!(w.data, vec![10., 14.]);
+        assert_eq!((u + v).data, vec![7., 10.]);
+        let m = Vector::from([Modular::<7>::new(3), Modular::new(5)]);
+        assert_eq!(
+            m * Modular::new(4),
+            Vector::from([Modular::new(5), Modular::new(6)])
+        );
+        assert_eq!((3i64 * Vector::from([1i64, 2])).data, vec![3, 6]);
+    }
 }
@@ -0,0 +1,656 @@
+use num_traits::{Float, Zero};
+
+use crate::{error::MatrixError, ex04::Modulus, matrix::Matrix, scalar::Scalar, vector::Vector};
+
+/// A borrowed, read-only window over a rectangular region of a Matrix or a Vector.
+///
+/// Rows of the region are `cols` consecutive elements, and consecutive rows are `stride`
+/// elements apart in the underlying buffer.
+#[derive(Debug)]
+pub struct MatrixView<'a, K> {
+    data: &'a [K],
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+impl<K> Clone for MatrixView<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for MatrixView<'_, K> {}
+
+/// A borrowed, mutable window over a rectangular region of a Matrix or a Vector.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, K> {
+    data: &'a mut [K],
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+/// Computes the position of a block in a row-major buffer, checking its bounds.
+///
+/// # Arguments
+///
+/// * `shape` - The `[columns, rows]` shape of the parent region.
+/// * `r` - The first row of the block.
+/// * `c` - The first column of the block.
+/// * `h` - The number of rows of the block.
+/// * `w` - The number of columns of the block.
+/// * `stride` - The distance between two rows of the parent region.
+///
+/// # Returns
+///
+/// The offset of the first element of the block.
+///
+/// # Panics
+///
+/// Panics if the block does not fit in the parent region.
+fn block_offset(shape: [usize; 2], r: usize, c: usize, h: usize, w: usize, stride: usize) -> usize {
+    if r + h > shape[1] || c + w > shape[0] {
+        panic!(
+            "Block of {}x{} at ({}, {}) is out of bounds for a {}x{} matrix",
+            h, w, r, c, shape[1], shape[0]
+        );
+    }
+    if h == 0 || w == 0 {
+        return 0;
+    }
+    r * stride + c
+}
+
+impl<'a, K> MatrixView<'a, K> {
+    /// Returns the shape of the view as a 2-element array.
+    ///
+    /// # Returns
+    ///
+    /// An array containing the number of columns and rows in the view.
+    pub fn shape(&self) -> [usize; 2] {
+        [self.cols, self.rows]
+    }
+
+    /// Returns the number of elements in the view.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows times the number of columns.
+    pub fn size(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    /// Returns a reference to an element of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row of the element.
+    /// * `j` - The column of the element.
+    ///
+    /// # Returns
+    ///
+    /// The element, or `None` if the position is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a K> {
+        if i >= self.rows || j >= self.cols {
+            return None;
+        }
+        Some(&self.data[i * self.stride + j])
+    }
+
+    /// Returns one row of the view as a contiguous slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row to return.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the `cols` elements of the row.
+    pub(crate) fn row_slice(&self, i: usize) -> &'a [K] {
+        &self.data[i * self.stride..i * self.stride + self.cols]
+    }
+
+    /// Iterates over the elements of the view in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements.
+    pub fn iter(&self) -> impl Iterator<Item = &'a K> + 'a {
+        let view = *self;
+        (0..view.rows).flat_map(move |i| view.row_slice(i).iter())
+    }
+
+    /// Returns a view over one row.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row to view.
+    ///
+    /// # Returns
+    ///
+    /// A view with a single row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, i: usize) -> MatrixView<'a, K> {
+        self.block(i, 0, 1, self.cols)
+    }
+
+    /// Returns a view over one column.
+    ///
+    /// # Arguments
+    ///
+    /// * `j` - The column to view.
+    ///
+    /// # Returns
+    ///
+    /// A view with a single column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn col(&self, j: usize) -> MatrixView<'a, K> {
+        self.block(0, j, self.rows, 1)
+    }
+
+    /// Returns a view over a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The first row of the block.
+    /// * `c` - The first column of the block.
+    /// * `h` - The number of rows of the block.
+    /// * `w` - The number of columns of the block.
+    ///
+    /// # Returns
+    ///
+    /// A view with `h` rows and `w` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the view.
+    pub fn block(&self, r: usize, c: usize, h: usize, w: usize) -> MatrixView<'a, K> {
+        let offset = block_offset(self.shape(), r, c, h, w, self.stride);
+        MatrixView {
+            data: &self.data[offset..],
+            rows: h,
+            cols: w,
+            stride: self.stride,
+        }
+    }
+}
+
+impl<K: Clone> MatrixView<'_, K> {
+    /// Copies the elements of the view into a new Matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Matrix with the shape and elements of the view.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix {
+            data: self.iter().cloned().collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Copies the elements of the view into a new Vector.
+    ///
+    /// # Returns
+    ///
+    /// A Vector with the elements of the view in row-major order.
+    pub fn to_vector(&self) -> Vector<K> {
+        Vector::from(self.iter().cloned().collect::<Vec<K>>())
+    }
+}
+
+impl<K: Scalar> MatrixView<'_, K> {
+    /// Computes the dot product of two views seen as vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another view with the same number of elements.
+    ///
+    /// # Returns
+    ///
+    /// The dot product of the two views.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views do not have the same number of elements.
+    pub fn dot(&self, v: &MatrixView<K>) -> K {
+        self.try_dot(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Computes the dot product of two views seen as vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Another view with the same number of elements.
+    ///
+    /// # Returns
+    ///
+    /// The dot product of the two views, or a `MatrixError::ShapeMismatch` if the views
+    /// do not have the same number of elements.
+    pub fn try_dot(&self, v: &MatrixView<K>) -> Result<K, MatrixError> {
+        if self.size() != v.size() {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, self.size()],
+                found: [1, v.size()],
+            });
+        }
+        Ok(self
+            .iter()
+            .zip(v.iter())
+            .fold(K::zero(), |res, (a, b)| b.clone().mul_add(a.clone(), res)))
+    }
+
+    /// Multiplies the view by a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with the result of the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the vector differs from the number of columns of the view.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        self.try_mul_vec(vec).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies the view by a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with the result of the operation, or a `MatrixError::ShapeMismatch`
+    /// if the size of the vector differs from the number of columns of the view.
+    pub fn try_mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MatrixError> {
+        if self.cols != vec.size() {
+            return Err(MatrixError::ShapeMismatch {
+                expected: [1, self.cols],
+                found: vec.shape(),
+            });
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                self.row_slice(i)
+                    .iter()
+                    .zip(vec.data.iter())
+                    .fold(K::zero(), |res, (a, x)| x.clone().mul_add(a.clone(), res))
+            })
+            .collect())
+    }
+}
+
+impl<K: Modulus> MatrixView<'_, K> {
+    /// Computes the 1-norm of the view seen as a vector.
+    ///
+    /// # Returns
+    ///
+    /// The 1-norm of the view.
+    pub fn norm_1(&self) -> K::Real {
+        self.iter()
+            .fold(K::Real::zero(), |res, x| res + x.modulus())
+    }
+
+    /// Computes the Euclidean norm of the view seen as a vector.
+    ///
+    /// # Returns
+    ///
+    /// The Euclidean norm of the view.
+    pub fn norm(&self) -> K::Real {
+        self.iter()
+            .map(|x| x.modulus())
+            .fold(K::Real::zero(), |res, modulus| {
+                modulus.mul_add(modulus, res)
+            })
+            .sqrt()
+    }
+
+    /// Computes the infinity norm of the view seen as a vector.
+    ///
+    /// # Returns
+    ///
+    /// The infinity norm of the view.
+    pub fn norm_inf(&self) -> K::Real {
+        self.iter()
+            .fold(K::Real::zero(), |res, x| res.max(x.modulus()))
+    }
+}
+
+impl<'a, K> MatrixViewMut<'a, K> {
+    /// Returns the shape of the view as a 2-element array.
+    ///
+    /// # Returns
+    ///
+    /// An array containing the number of columns and rows in the view.
+    pub fn shape(&self) -> [usize; 2] {
+        [self.cols, self.rows]
+    }
+
+    /// Reborrows the view as a read-only view.
+    ///
+    /// # Returns
+    ///
+    /// A MatrixView over the same region.
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            stride: self.stride,
+        }
+    }
+
+    /// Returns a mutable reference to an element of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row of the element.
+    /// * `j` - The column of the element.
+    ///
+    /// # Returns
+    ///
+    /// The element, or `None` if the position is out of bounds.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut K> {
+        if i >= self.rows || j >= self.cols {
+            return None;
+        }
+        Some(&mut self.data[i * self.stride + j])
+    }
+
+    /// Iterates mutably over the elements of the view in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut K> {
+        let cols = self.cols;
+        self.data
+            .chunks_mut(self.stride.max(1))
+            .take(self.rows)
+            .flat_map(move |row| row[..cols].iter_mut())
+    }
+
+    /// Returns a mutable view over a block, consuming this view.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The first row of the block.
+    /// * `c` - The first column of the block.
+    /// * `h` - The number of rows of the block.
+    /// * `w` - The number of columns of the block.
+    ///
+    /// # Returns
+    ///
+    /// A mutable view with `h` rows and `w` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the view.
+    pub fn into_block(self, r: usize, c: usize, h: usize, w: usize) -> MatrixViewMut<'a, K> {
+        let offset = block_offset(self.shape(), r, c, h, w, self.stride);
+        MatrixViewMut {
+            data: &mut self.data[offset..],
+            rows: h,
+            cols: w,
+            stride: self.stride,
+        }
+    }
+}
+
+impl<K: Clone> MatrixViewMut<'_, K> {
+    /// Overwrites the elements of the view with the elements of another view.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - A view with the same shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views do not have the same shape.
+    pub fn copy_from(&mut self, src: &MatrixView<K>) {
+        if self.shape() != src.shape() {
+            panic!(
+                "{}",
+                MatrixError::ShapeMismatch {
+                    expected: self.shape(),
+                    found: src.shape(),
+                }
+            );
+        }
+        for (dst, value) in self.iter_mut().zip(src.iter()) {
+            *dst = value.clone();
+        }
+    }
+
+    /// Sets every element of the view to the same value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to write.
+    pub fn fill(&mut self, value: K) {
+        for dst in self.iter_mut() {
+            *dst = value.clone();
+        }
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Borrows the whole Matrix as a view.
+    ///
+    /// # Returns
+    ///
+    /// A MatrixView over every element of the Matrix.
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            stride: self.cols,
+        }
+    }
+
+    /// Borrows the whole Matrix as a mutable view.
+    ///
+    /// # Returns
+    ///
+    /// A MatrixViewMut over every element of the Matrix.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, K> {
+        MatrixViewMut {
+            data: &mut self.data,
+            rows: self.rows,
+            cols: self.cols,
+            stride: self.cols,
+        }
+    }
+
+    /// Borrows one row of the Matrix without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row to view.
+    ///
+    /// # Returns
+    ///
+    /// A view with a single row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, i: usize) -> MatrixView<'_, K> {
+        self.as_view().row(i)
+    }
+
+    /// Borrows one column of the Matrix without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `j` - The column to view.
+    ///
+    /// # Returns
+    ///
+    /// A view with a single column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn col(&self, j: usize) -> MatrixView<'_, K> {
+        self.as_view().col(j)
+    }
+
+    /// Borrows a block of the Matrix without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The first row of the block.
+    /// * `c` - The first column of the block.
+    /// * `h` - The number of rows of the block.
+    /// * `w` - The number of columns of the block.
+    ///
+    /// # Returns
+    ///
+    /// A view with `h` rows and `w` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the Matrix.
+    pub fn block(&self, r: usize, c: usize, h: usize, w: usize) -> MatrixView<'_, K> {
+        self.as_view().block(r, c, h, w)
+    }
+
+    /// Mutably borrows one row of the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The row to view.
+    ///
+    /// # Returns
+    ///
+    /// A mutable view with a single row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, K> {
+        let cols = self.cols;
+        self.as_view_mut().into_block(i, 0, 1, cols)
+    }
+
+    /// Mutably borrows one column of the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `j` - The column to view.
+    ///
+    /// # Returns
+    ///
+    /// A mutable view with a single column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, K> {
+        let rows = self.rows;
+        self.as_view_mut().into_block(0, j, rows, 1)
+    }
+
+    /// Mutably borrows a block of the Matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The first row of the block.
+    /// * `c` - The first column of the block.
+    /// * `h` - The number of rows of the block.
+    /// * `w` - The number of columns of the block.
+    ///
+    /// # Returns
+    ///
+    /// A mutable view with `h` rows and `w` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the Matrix.
+    pub fn block_mut(&mut self, r: usize, c: usize, h: usize, w: usize) -> MatrixViewMut<'_, K> {
+        self.as_view_mut().into_block(r, c, h, w)
+    }
+}
+
+impl<K> Vector<K> {
+    /// Borrows the Vector as a single column view.
+    ///
+    /// # Returns
+    ///
+    /// A MatrixView with one column and as many rows as the Vector has elements.
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            data: &self.data,
+            rows: self.size(),
+            cols: 1,
+            stride: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_col_block() {
+        let m = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        assert_eq!(m.row(1).to_vector(), Vector::from([4., 5., 6.]));
+        assert_eq!(m.col(2).to_vector(), Vector::from([3., 6., 9.]));
+        assert_eq!(m.col(2).shape(), [1, 3]);
+        let b = m.block(1, 1, 2, 2);
+        assert_eq!(b.shape(), [2, 2]);
+        assert_eq!(b.get(1, 0), Some(&8.));
+        assert_eq!(b.get(2, 0), None);
+        assert_eq!(b.to_matrix(), Matrix::from([[5., 6.], [8., 9.]]));
+        assert_eq!(b.col(1).to_vector(), Vector::from([6., 9.]));
+        assert_eq!(m.block(0, 1, 0, 2).size(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_block_out_of_bounds() {
+        let m = Matrix::from([[1., 2.], [3., 4.]]);
+        m.block(1, 1, 2, 1);
+    }
+
+    #[test]
+    fn test_read_operations() {
+        let m = Matrix::from([[1., 2., 3.], [4., 5., 6.], [-7., 8., 9.]]);
+        assert_eq!(m.row(0).dot(&m.col(0)), -12.);
+        let v = Vector::from([1., 1.]);
+        assert!(m.row(1).try_dot(&v.as_view()).is_err());
+        assert_eq!(m.col(0).norm_1(), 12.);
+        assert_eq!(m.col(0).norm_inf(), 7.);
+        assert_eq!(m.row(0).block(0, 0, 1, 2).norm(), 5f64.sqrt());
+        assert_eq!(m.block(1, 1, 2, 2).mul_vec(&v), Vector::from([11., 17.]));
+    }
+
+    #[test]
+    fn test_mutable_views() {
+        let mut m = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        m.col_mut(1).fill(0.);
+        assert_eq!(m.data, vec![1., 0., 3., 4., 0., 6.]);
+        let src = Matrix::from([[7., 8.]]);
+        m.block_mut(1, 1, 1, 2).copy_from(&src.as_view());
+        assert_eq!(m.data, vec![1., 0., 3., 4., 7., 8.]);
+        if let Some(x) = m.row_mut(0).get_mut(0, 2) {
+            *x = -3.;
+        }
+        assert_eq!(m.row(0).to_vector(), Vector::from([1., 0., -3.]));
+    }
+}